@@ -1,7 +1,8 @@
 use dioxus::prelude::*;
 use dioxus_maplibre::{
     Map, MapHandle, GeoJsonSourceOptions, LayerOptions,
-    LayerClickEvent, LayerHoverEvent, FeatureIdentifier, LatLng,
+    LayerClickEvent, LayerHoverEvent, LayerHoverOptions, LatLng, MapMouseEvent,
+    FeatureId, FeatureIdentifier, MapFeatureState,
 };
 use serde_json::json;
 
@@ -10,7 +11,8 @@ pub fn Interaction() -> Element {
     let mut map_handle = use_signal(|| None::<MapHandle>);
     let mut clicked_feature = use_signal(|| None::<String>);
     let mut hovered_feature = use_signal(|| None::<String>);
-    let mut prev_hover_id = use_signal(|| None::<i64>);
+    let mut selected_id = use_signal(|| None::<FeatureId>);
+    let mut cursor = use_signal(|| None::<LatLng>);
     let style: Signal<String> = use_context();
 
     rsx! {
@@ -47,58 +49,57 @@ pub fn Interaction() -> Element {
                                     "case", ["boolean", ["feature-state", "hover"], false],
                                     "#f59e0b", "#3b82f6"
                                 ],
-                                "circle-stroke-width": 2,
+                                "circle-stroke-width": [
+                                    "case", ["boolean", ["feature-state", "selected"], false],
+                                    4, 2
+                                ],
                                 "circle-stroke-color": "#fff"
                             }))
                         );
 
                         // Register interaction handlers
                         handle.on_layer_click("interactive-circles");
-                        handle.on_layer_hover("interactive-circles");
+                        handle.on_layer_hover_with(
+                            "interactive-circles",
+                            LayerHoverOptions { feature_state: true },
+                        );
 
                         map_handle.set(Some(handle));
                     },
+                    on_mouse_move: move |e: MapMouseEvent| cursor.set(Some(e.latlng)),
+                    on_mouse_out: move |_: MapMouseEvent| cursor.set(None),
                     on_layer_click: move |e: LayerClickEvent| {
                         let name = e.properties.get("name")
                             .and_then(|v| v.as_str())
                             .unwrap_or("Unknown");
-                        clicked_feature.set(Some(format!("{name} (id: {:?})", e.feature_id)));
+                        let id = e.feature_id.as_ref().map_or_else(|| "none".to_string(), ToString::to_string);
+                        clicked_feature.set(Some(format!("{name} (id: {id})")));
+                        selected_id.set(e.feature_id);
                     },
                     on_layer_hover: move |e: LayerHoverEvent| {
-                        let Some(ref map) = *map_handle.read() else { return };
-
-                        // Remove previous hover state
-                        if let Some(prev_id) = prev_hover_id() {
-                            map.remove_feature_state(&FeatureIdentifier {
-                                source: "interactive".into(),
-                                id: prev_id,
-                                source_layer: None,
-                            });
-                        }
-
-                        // Set new hover state
-                        if let Some(id) = e.feature_id {
-                            map.set_feature_state(
-                                &FeatureIdentifier {
-                                    source: "interactive".into(),
-                                    id,
-                                    source_layer: None,
-                                },
-                                json!({"hover": true}),
-                            );
-                            prev_hover_id.set(Some(id));
-
+                        // The hover feature-state is kept in sync by the bridge
+                        if e.is_leave() {
+                            hovered_feature.set(None);
+                        } else {
                             let name = e.properties
                                 .as_ref()
                                 .and_then(|p| p.get("name"))
                                 .and_then(|v| v.as_str())
                                 .unwrap_or("Unknown");
                             hovered_feature.set(Some(name.to_string()));
-                        } else {
-                            prev_hover_id.set(None);
-                            hovered_feature.set(None);
                         }
                     },
+
+                    if let Some(id) = selected_id() {
+                        MapFeatureState {
+                            feature: FeatureIdentifier {
+                                source: "interactive".into(),
+                                id,
+                                source_layer: None,
+                            },
+                            state: json!({"selected": true}),
+                        }
+                    }
                 }
             }
             div { style: "width: 280px; background: #16213e; color: #e0e0e0; padding: 16px; font-size: 13px;",
@@ -106,6 +107,13 @@ pub fn Interaction() -> Element {
                 p { "Hover over circles to see feature state changes." }
                 p { "Click circles to select them." }
 
+                if let Some(latlng) = cursor() {
+                    p { "data-testid": "cursor-info",
+                        span { style: "color: #9ca3af;", "Cursor: " }
+                        "{latlng.lat:.5}, {latlng.lng:.5}"
+                    }
+                }
+
                 if let Some(name) = hovered_feature() {
                     p { "data-testid": "hover-info",
                         span { style: "color: #f59e0b;", "Hovering: " }
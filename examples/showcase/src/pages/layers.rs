@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_maplibre::{
-    Map, MapHandle, GeoJsonSourceOptions, LayerOptions, LatLng,
+    Map, MapHandle, GeoJsonSourceOptions, LayerOptions, LayerSlot, LatLng,
 };
 use serde_json::json;
 
@@ -60,6 +60,7 @@ pub fn Layers() -> Element {
 
                         // Line layer
                         handle.add_layer(LayerOptions::line("route", "route-line")
+                            .slot(LayerSlot::BelowLabels)
                             .paint(json!({
                                 "line-color": "#ef4444",
                                 "line-width": 4,
@@ -89,6 +90,7 @@ pub fn Layers() -> Element {
 
                         // Fill layer
                         handle.add_layer(LayerOptions::fill("area", "area-fill")
+                            .slot(LayerSlot::BelowRoads)
                             .paint(json!({
                                 "fill-color": "#22c55e",
                                 "fill-opacity": 0.3
@@ -101,7 +103,7 @@ pub fn Layers() -> Element {
             }
             div { style: "width: 280px; background: #16213e; color: #e0e0e0; padding: 16px; font-size: 13px;",
                 h3 { style: "margin: 0 0 12px 0;", "Layers" }
-                p { "Circle, line, and fill layers." }
+                p { "Circle, line, and fill layers. The fill is slotted below roads and the route below labels." }
 
                 if let Some(ref map) = *map_handle.read() {
                     div { style: "display: flex; flex-direction: column; gap: 8px; margin-top: 16px;",
@@ -1,5 +1,5 @@
 use dioxus::prelude::*;
-use dioxus_maplibre::{Map, MapHandle, MarkerOptions, MarkerClickEvent, MarkerDragEndEvent, LatLng};
+use dioxus_maplibre::{Map, MapHandle, MapMarker, MarkerOptions, MarkerClickEvent, MarkerDragEndEvent, LatLng};
 
 #[component]
 pub fn Markers() -> Element {
@@ -7,6 +7,7 @@ pub fn Markers() -> Element {
     let mut clicked_marker = use_signal(|| None::<String>);
     let mut marker_count = use_signal(|| 0u32);
     let mut drag_position = use_signal(|| None::<LatLng>);
+    let mut badge_clicks = use_signal(|| 0u32);
     let style: Signal<String> = use_context();
 
     rsx! {
@@ -44,6 +45,18 @@ pub fn Markers() -> Element {
                     on_marker_dragend: move |e: MarkerDragEndEvent| {
                         drag_position.set(Some(e.latlng));
                     },
+
+                    // RSX marker content with its own event handler
+                    MapMarker {
+                        id: "badge",
+                        position: LatLng::new(60.20, 25.05),
+                        div {
+                            "data-testid": "badge-marker",
+                            style: "padding: 4px 8px; border-radius: 12px; background: #f59e0b; color: #111; font-weight: 600; cursor: pointer;",
+                            onclick: move |_| badge_clicks += 1,
+                            "Clicks: {badge_clicks}"
+                        }
+                    }
                 }
             }
             div { style: "width: 280px; background: #16213e; color: #e0e0e0; padding: 16px; font-size: 13px;",
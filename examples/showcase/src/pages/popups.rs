@@ -1,11 +1,12 @@
 use dioxus::prelude::*;
-use dioxus_maplibre::{LatLng, Map, MapHandle, PopupOptions};
+use dioxus_maplibre::{LatLng, Map, MapHandle, MapPopup, PopupOptions};
 
 #[component]
 pub fn Popups() -> Element {
     let mut map_handle = use_signal(|| None::<MapHandle>);
     let mut popup_count = use_signal(|| 3u32);
     let mut next_popup_id = use_signal(|| 4u32);
+    let mut likes = use_signal(|| 0u32);
     let style: Signal<String> = use_context();
 
     rsx! {
@@ -49,6 +50,25 @@ pub fn Popups() -> Element {
 
                         map_handle.set(Some(handle));
                     },
+
+                    // RSX popup content with its own event handler
+                    MapPopup {
+                        id: "rsx-popup",
+                        position: LatLng::new(60.180, 24.925),
+                        options: PopupOptions {
+                            close_on_click: Some(false),
+                            ..Default::default()
+                        },
+                        div { "data-testid": "rsx-popup",
+                            b { "Töölö" }
+                            p { "Likes: {likes}" }
+                            button {
+                                "data-testid": "like-button",
+                                onclick: move |_| likes += 1,
+                                "Like"
+                            }
+                        }
+                    }
                 }
             }
             div { style: "width: 280px; background: #16213e; color: #e0e0e0; padding: 16px; font-size: 13px;",
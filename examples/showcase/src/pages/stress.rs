@@ -665,7 +665,8 @@ pub fn Stress() -> Element {
                     center: LatLng::new(64.6, 26.5),
                     zoom: 4.6,
                     on_ready: move |handle: MapHandle| {
-                        add_stress_layers(&handle, circle_size_multiplier());
+                        let size_multiplier = circle_size_multiplier();
+                        handle.batch(|b| add_stress_layers(b, size_multiplier));
                         map_handle.set(Some(handle.clone()));
                         debug_log.write().push("on_ready: map + base stress layers initialized".to_string());
 
@@ -704,19 +705,25 @@ pub fn Stress() -> Element {
                                 .map(ToString::to_string);
 
                             if icons_ready {
-                                add_shape_icon_layers(&map);
-                                force_shape_icon_layouts(&map);
-                                add_shape_fast_layers(&map);
-                                apply_shape_render_mode(&map, fast_render_mode());
+                                let fast_mode = fast_render_mode();
+                                map.batch(|b| {
+                                    add_shape_icon_layers(b);
+                                    force_shape_icon_layouts(b);
+                                    add_shape_fast_layers(b);
+                                    apply_shape_render_mode(b, fast_mode);
+                                });
                                 shape_render_mode.set("Icons".to_string());
                                 debug_log.write().push(
                                     "icon install: OK, added icon-based symbol layers + forced icon layouts"
                                         .to_string(),
                                 );
                             } else {
-                                add_shape_text_layers_fallback(&map);
-                                add_shape_fast_layers(&map);
-                                apply_shape_render_mode(&map, fast_render_mode());
+                                let fast_mode = fast_render_mode();
+                                map.batch(|b| {
+                                    add_shape_text_layers_fallback(b);
+                                    add_shape_fast_layers(b);
+                                    apply_shape_render_mode(b, fast_mode);
+                                });
                                 if let Some(error) = icon_error {
                                     debug_log.write().push(format!(
                                         "icon install: FAILED, using text fallback ({error})"
@@ -979,7 +986,7 @@ pub fn Stress() -> Element {
                             ));
 
                             if let Some(ref map) = *map_handle.read() {
-                                apply_shape_render_mode(map, new_mode);
+                                map.batch(|b| apply_shape_render_mode(b, new_mode));
                                 let map = map.clone();
                                 spawn(async move {
                                     if let Some(snapshot) =
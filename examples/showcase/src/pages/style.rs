@@ -1,5 +1,5 @@
 use dioxus::prelude::*;
-use dioxus_maplibre::{LatLng, Map, MapHandle};
+use dioxus_maplibre::{LatLng, Map, MapHandle, SetStyleOptions};
 
 struct StyleEntry {
     name: &'static str,
@@ -18,6 +18,7 @@ const STYLES: &[StyleEntry] = &[
 pub fn StyleSwitcher() -> Element {
     let mut map_handle = use_signal(|| None::<MapHandle>);
     let mut current_style = use_signal(|| "Dark Matter".to_string());
+    let mut diff = use_signal(|| false);
     let style: Signal<String> = use_context();
 
     rsx! {
@@ -35,6 +36,14 @@ pub fn StyleSwitcher() -> Element {
             div { style: "width: 280px; background: #16213e; color: #e0e0e0; padding: 16px; font-size: 13px;",
                 h3 { style: "margin: 0 0 12px 0;", "Style Switcher" }
                 p { "Current: {current_style}" }
+                label { style: "display: flex; gap: 6px; align-items: center;",
+                    input {
+                        r#type: "checkbox",
+                        checked: diff(),
+                        onchange: move |e| diff.set(e.checked()),
+                    }
+                    "Diff styles (patch only what changed)"
+                }
 
                 if let Some(ref map) = *map_handle.read() {
                     div { style: "display: flex; flex-direction: column; gap: 8px; margin-top: 12px;",
@@ -47,7 +56,10 @@ pub fn StyleSwitcher() -> Element {
                                     button {
                                         style: "padding: 8px; border-radius: 4px; border: none; background: #333; color: white; cursor: pointer; text-align: left; font-size: 12px;",
                                         onclick: move |_| {
-                                            map.set_style(url);
+                                            map.set_style_with(url, SetStyleOptions {
+                                                diff: diff(),
+                                                ..Default::default()
+                                            });
                                             current_style.set(name.to_string());
                                         },
                                         "{name}"
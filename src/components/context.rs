@@ -1,10 +1,87 @@
 //! Shared map-handle context and hook helpers.
 
-use dioxus::prelude::{Signal, try_use_context};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
 
+use dioxus::prelude::{
+    EventHandler, ReadSignal, Signal, try_consume_context, try_use_context, use_hook,
+};
+
+use crate::events::{LayerClickEvent, LayerHoverEvent};
 use crate::handle::MapHandle;
+use crate::types::MapState;
 
 pub(crate) type MapHandleSignal = Signal<Option<MapHandle>>;
+pub(crate) type MapStateSignal = Signal<MapState>;
+
+/// Event handlers owned by one `MapLayer`.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(
+    not(any(target_arch = "wasm32", feature = "testing")),
+    allow(dead_code)
+)]
+pub(crate) struct LayerEventHandlers {
+    pub click: Option<EventHandler<LayerClickEvent>>,
+    pub dblclick: Option<EventHandler<LayerClickEvent>>,
+    pub context_menu: Option<EventHandler<LayerClickEvent>>,
+    pub hover: Option<EventHandler<LayerHoverEvent>>,
+    pub mouse_leave: Option<EventHandler<LayerHoverEvent>>,
+}
+
+/// Per-layer handlers keyed by layer ID, shared between `MapLayer`s and the
+/// `Map` event loop. Not reactive: dispatch only looks handlers up.
+///
+/// Each entry remembers the `MapLayer` that registered it, so a layer that
+/// unmounts after another one took over its ID leaves the new entry alone.
+#[derive(Clone, Default)]
+pub struct LayerHandlerRegistry(Rc<RefCell<LayerHandlerEntries>>);
+
+#[derive(Default)]
+struct LayerHandlerEntries {
+    next_owner: u64,
+    handlers: HashMap<String, (u64, LayerEventHandlers)>,
+}
+
+impl LayerHandlerRegistry {
+    /// A token identifying one registering `MapLayer`
+    pub(crate) fn new_owner(&self) -> u64 {
+        let mut entries = self.0.borrow_mut();
+        entries.next_owner += 1;
+        entries.next_owner
+    }
+
+    pub(crate) fn insert(&self, layer_id: &str, owner: u64, handlers: LayerEventHandlers) {
+        self.0
+            .borrow_mut()
+            .handlers
+            .insert(layer_id.to_string(), (owner, handlers));
+    }
+
+    /// Remove `layer_id`'s handlers if `owner` still holds them
+    pub(crate) fn remove(&self, layer_id: &str, owner: u64) {
+        let mut entries = self.0.borrow_mut();
+        if entries
+            .handlers
+            .get(layer_id)
+            .is_some_and(|(current, _)| *current == owner)
+        {
+            entries.handlers.remove(layer_id);
+        }
+    }
+
+    #[cfg_attr(
+        not(any(target_arch = "wasm32", feature = "testing")),
+        allow(dead_code)
+    )]
+    pub(crate) fn get(&self, layer_id: &str) -> Option<LayerEventHandlers> {
+        self.0
+            .borrow()
+            .handlers
+            .get(layer_id)
+            .map(|(_, handlers)| *handlers)
+    }
+}
 
 pub(crate) fn try_use_map_handle_signal() -> Option<MapHandleSignal> {
     try_use_context::<MapHandleSignal>()
@@ -16,3 +93,16 @@ pub(crate) fn try_use_map_handle_signal() -> Option<MapHandleSignal> {
 pub fn use_map_handle() -> Option<MapHandle> {
     try_use_map_handle_signal().and_then(|signal| signal())
 }
+
+/// Subscribe to the nearest `Map`'s camera and loading state.
+///
+/// The signal is fed from the map's move/zoom/rotate/pitch and load events, so
+/// reading it re-renders on changes without any JS round trips. Outside a `Map`
+/// subtree the returned signal stays at `MapState::default()`.
+pub fn use_map_state() -> ReadSignal<MapState> {
+    use_hook(|| {
+        try_consume_context::<MapStateSignal>()
+            .unwrap_or_else(|| Signal::new(MapState::default()))
+            .into()
+    })
+}
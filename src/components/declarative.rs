@@ -1,15 +1,24 @@
 //! Declarative map object components built on top of `MapHandle`.
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use dioxus::prelude::*;
 
+use crate::events::{LayerClickEvent, LayerHoverEvent};
 use crate::handle::MapHandle;
+use crate::interop::generate_element_id;
 use crate::options::{
-    ControlPosition, GeoJsonSourceOptions, ImageSourceOptions, LayerOptions, MarkerOptions,
-    PopupOptions, RasterDemSourceOptions, RasterSourceOptions, VectorSourceOptions,
+    ControlPosition, FeatureIdentifier, FogOptions, GeoJsonSourceOptions, ImageSourceOptions,
+    LayerHoverOptions, LayerOptions, MarkerOptions, PopupOptions, RasterDemSourceOptions,
+    RasterSourceOptions, SkyOptions, StyleImageOptions, StyleImageSource, TerrainOptions,
+    VectorSourceOptions,
 };
 use crate::types::LatLng;
 
-use super::context::try_use_map_handle_signal;
+use super::context::{
+    LayerEventHandlers, LayerHandlerRegistry, try_use_map_handle_signal, use_map_state,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum MapSourceKind {
@@ -36,10 +45,14 @@ struct SourceState {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
 struct LayerState {
     options: LayerOptions,
     register_click_events: bool,
     register_hover_events: bool,
+    register_dblclick_events: bool,
+    register_contextmenu_events: bool,
+    hover_feature_state: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -47,6 +60,8 @@ struct MarkerState {
     id: String,
     position: LatLng,
     options: MarkerOptions,
+    /// DOM ID of the rendered children used as marker content, if any
+    content_element_id: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -55,6 +70,21 @@ struct PopupState {
     position: LatLng,
     html: String,
     options: PopupOptions,
+    /// DOM ID of the rendered children used as popup content, if any
+    content_element_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct FeatureStateState {
+    feature: FeatureIdentifier,
+    state: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ImageState {
+    id: String,
+    source: StyleImageSource,
+    options: StyleImageOptions,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -109,6 +139,12 @@ fn remove_layer_bindings(map: &MapHandle, layer: &LayerState) {
     if layer.register_hover_events {
         map.off_layer_hover(&layer.options.id);
     }
+    if layer.register_dblclick_events {
+        map.off_layer_dblclick(&layer.options.id);
+    }
+    if layer.register_contextmenu_events {
+        map.off_layer_contextmenu(&layer.options.id);
+    }
     map.remove_layer(&layer.options.id);
 }
 
@@ -118,7 +154,18 @@ fn add_layer_bindings(map: &MapHandle, layer: &LayerState) {
         map.on_layer_click(&layer.options.id);
     }
     if layer.register_hover_events {
-        map.on_layer_hover(&layer.options.id);
+        map.on_layer_hover_with(
+            &layer.options.id,
+            LayerHoverOptions {
+                feature_state: layer.hover_feature_state,
+            },
+        );
+    }
+    if layer.register_dblclick_events {
+        map.on_layer_dblclick(&layer.options.id);
+    }
+    if layer.register_contextmenu_events {
+        map.on_layer_contextmenu(&layer.options.id);
     }
 }
 
@@ -199,6 +246,13 @@ pub fn MapSource(props: MapSourceProps) -> Element {
 }
 
 /// Declaratively mount a layer and remove it on unmount.
+///
+/// The `on_*` handlers receive only this layer's events, in addition to the
+/// `Map`'s global `on_layer_*` callbacks. Setting one registers the matching
+/// bridge listener, so `register_*_events` is only needed for the globals.
+///
+/// Set `options.slot` or `options.before_id` to mount at a fixed depth in the
+/// basemap instead of on top; the anchor is re-resolved after style switches.
 #[derive(Props, Clone, PartialEq)]
 pub struct MapLayerProps {
     pub options: LayerOptions,
@@ -206,6 +260,25 @@ pub struct MapLayerProps {
     pub register_click_events: bool,
     #[props(default = false)]
     pub register_hover_events: bool,
+    /// Keep a `hover` feature-state on the feature under the cursor, for
+    /// styling with `["feature-state", "hover"]`. Features need IDs.
+    #[props(default = false)]
+    pub hover_feature_state: bool,
+    /// Called when a feature in this layer is clicked.
+    #[props(optional)]
+    pub on_click: Option<EventHandler<LayerClickEvent>>,
+    /// Called when a feature in this layer is double-clicked.
+    #[props(optional)]
+    pub on_dblclick: Option<EventHandler<LayerClickEvent>>,
+    /// Called when a feature in this layer is right-clicked.
+    #[props(optional)]
+    pub on_context_menu: Option<EventHandler<LayerClickEvent>>,
+    /// Called when the cursor enters the layer or moves to another of its features.
+    #[props(optional)]
+    pub on_hover: Option<EventHandler<LayerHoverEvent>>,
+    /// Called when the cursor leaves this layer.
+    #[props(optional)]
+    pub on_mouse_leave: Option<EventHandler<LayerHoverEvent>>,
 }
 
 #[component]
@@ -213,10 +286,41 @@ pub fn MapLayer(props: MapLayerProps) -> Element {
     let handle_signal = try_use_map_handle_signal();
     let mut applied_layer = use_signal(|| None::<LayerState>);
 
+    // Keep this layer's handlers registered under its current ID.
+    let registry = try_use_context::<LayerHandlerRegistry>();
+    let registered_id = use_hook(|| Rc::new(RefCell::new(None::<String>)));
+    let owner = use_hook(|| registry.as_ref().map_or(0, LayerHandlerRegistry::new_owner));
+    if let Some(registry) = &registry {
+        let mut registered = registered_id.borrow_mut();
+        if let Some(previous_id) = registered.as_deref()
+            && previous_id != props.options.id
+        {
+            registry.remove(previous_id, owner);
+        }
+        registry.insert(
+            &props.options.id,
+            owner,
+            LayerEventHandlers {
+                click: props.on_click,
+                dblclick: props.on_dblclick,
+                context_menu: props.on_context_menu,
+                hover: props.on_hover,
+                mouse_leave: props.on_mouse_leave,
+            },
+        );
+        *registered = Some(props.options.id.clone());
+    }
+
     let desired_layer = LayerState {
         options: props.options.clone(),
-        register_click_events: props.register_click_events,
-        register_hover_events: props.register_hover_events,
+        register_click_events: props.register_click_events || props.on_click.is_some(),
+        register_hover_events: props.register_hover_events
+            || props.hover_feature_state
+            || props.on_hover.is_some()
+            || props.on_mouse_leave.is_some(),
+        register_dblclick_events: props.on_dblclick.is_some(),
+        register_contextmenu_events: props.on_context_menu.is_some(),
+        hover_feature_state: props.hover_feature_state,
     };
 
     use_effect(move || {
@@ -247,29 +351,54 @@ pub fn MapLayer(props: MapLayerProps) -> Element {
         {
             remove_layer_bindings(&map, layer);
         }
+        if let Some(registry) = &registry
+            && let Some(layer_id) = registered_id.borrow().as_deref()
+        {
+            registry.remove(layer_id, owner);
+        }
     });
 
     rsx! {}
 }
 
+fn add_marker(map: &MapHandle, marker: &MarkerState) {
+    match &marker.content_element_id {
+        Some(element_id) => map.add_marker_with_element(
+            &marker.id,
+            marker.position,
+            marker.options.clone(),
+            element_id,
+        ),
+        None => map.add_marker(&marker.id, marker.position, marker.options.clone()),
+    }
+}
+
 /// Declaratively mount a marker and remove it on unmount.
+///
+/// When `children` are given they are rendered as the marker's element in
+/// place of the default pin, keeping their reactivity and event handlers.
 #[derive(Props, Clone, PartialEq)]
 pub struct MapMarkerProps {
     pub id: String,
     pub position: LatLng,
     #[props(default)]
     pub options: MarkerOptions,
+    #[props(default)]
+    pub children: Element,
 }
 
 #[component]
 pub fn MapMarker(props: MapMarkerProps) -> Element {
     let handle_signal = try_use_map_handle_signal();
     let mut applied_marker = use_signal(|| None::<MarkerState>);
+    let content_element_id = use_hook(|| generate_element_id("marker_content"));
+    let has_content = props.children != VNode::empty();
 
     let desired_marker = MarkerState {
         id: props.id,
         position: props.position,
         options: props.options,
+        content_element_id: has_content.then(|| content_element_id.clone()),
     };
 
     use_effect(move || {
@@ -286,7 +415,10 @@ pub fn MapMarker(props: MapMarkerProps) -> Element {
         }
 
         if let Some(previous) = &previous {
-            if previous.id == desired_marker.id && previous.options == desired_marker.options {
+            if previous.id == desired_marker.id
+                && previous.options == desired_marker.options
+                && previous.content_element_id == desired_marker.content_element_id
+            {
                 map.update_marker_position(&desired_marker.id, desired_marker.position);
                 applied_marker.set(Some(desired_marker.clone()));
                 return;
@@ -294,11 +426,7 @@ pub fn MapMarker(props: MapMarkerProps) -> Element {
             map.remove_marker(&previous.id);
         }
 
-        map.add_marker(
-            &desired_marker.id,
-            desired_marker.position,
-            desired_marker.options.clone(),
-        );
+        add_marker(&map, &desired_marker);
         applied_marker.set(Some(desired_marker.clone()));
     });
 
@@ -311,29 +439,65 @@ pub fn MapMarker(props: MapMarkerProps) -> Element {
         }
     });
 
-    rsx! {}
+    // The hidden host keeps a stable spot in the DOM; only the inner element
+    // is moved into the marker, so Dioxus keeps diffing the children in place.
+    rsx! {
+        if has_content {
+            div { style: "display: none;",
+                div { id: "{content_element_id}", class: "maplibre-marker-content", {props.children} }
+            }
+        }
+    }
+}
+
+fn add_popup(map: &MapHandle, popup: &PopupState) {
+    match &popup.content_element_id {
+        Some(element_id) => {
+            map.add_popup_with_element(
+                &popup.id,
+                popup.position,
+                element_id,
+                popup.options.clone(),
+            );
+        }
+        None => map.add_popup(
+            &popup.id,
+            popup.position,
+            &popup.html,
+            popup.options.clone(),
+        ),
+    }
 }
 
 /// Declaratively mount a popup and remove it on unmount.
+///
+/// Content comes from `children` when given, so buttons and signals inside the
+/// popup work like any other Dioxus subtree; otherwise `html` is used.
 #[derive(Props, Clone, PartialEq)]
 pub struct MapPopupProps {
     pub id: String,
     pub position: LatLng,
+    #[props(default)]
     pub html: String,
     #[props(default)]
     pub options: PopupOptions,
+    #[props(default)]
+    pub children: Element,
 }
 
 #[component]
 pub fn MapPopup(props: MapPopupProps) -> Element {
     let handle_signal = try_use_map_handle_signal();
     let mut applied_popup = use_signal(|| None::<PopupState>);
+    let content_element_id = use_hook(|| generate_element_id("popup_content"));
+    let has_content = props.children != VNode::empty();
 
     let desired_popup = PopupState {
         id: props.id,
         position: props.position,
         html: props.html,
         options: props.options,
+        content_element_id: has_content.then(|| content_element_id.clone()),
     };
 
     use_effect(move || {
@@ -353,12 +517,7 @@ pub fn MapPopup(props: MapPopupProps) -> Element {
             map.remove_popup(&previous.id);
         }
 
-        map.add_popup(
-            &desired_popup.id,
-            desired_popup.position,
-            &desired_popup.html,
-            desired_popup.options.clone(),
-        );
+        add_popup(&map, &desired_popup);
         applied_popup.set(Some(desired_popup.clone()));
     });
 
@@ -371,7 +530,13 @@ pub fn MapPopup(props: MapPopupProps) -> Element {
         }
     });
 
-    rsx! {}
+    rsx! {
+        if has_content {
+            div { style: "display: none;",
+                div { id: "{content_element_id}", class: "maplibre-popup-content", {props.children} }
+            }
+        }
+    }
 }
 
 /// Declaratively add a control to the map.
@@ -424,3 +589,256 @@ pub fn MapControl(props: MapControlProps) -> Element {
 
     rsx! {}
 }
+
+/// Remove the keys `applied` set, leaving state from other writers alone.
+fn remove_applied_feature_state(map: &MapHandle, applied: &FeatureStateState) {
+    match applied.state.as_object() {
+        Some(keys) => {
+            for key in keys.keys() {
+                map.remove_feature_state_key(&applied.feature, key);
+            }
+        }
+        None => map.remove_feature_state(&applied.feature),
+    }
+}
+
+/// Declaratively set a feature's state and remove it on unmount.
+///
+/// Keys dropped from `state` between renders are removed from the feature;
+/// moving to another `feature` clears the previous one first. MapLibre drops
+/// feature state when the style reloads, so the state is set again each time
+/// the style finishes loading.
+#[derive(Props, Clone, PartialEq, Eq)]
+pub struct MapFeatureStateProps {
+    pub feature: FeatureIdentifier,
+    pub state: serde_json::Value,
+}
+
+#[component]
+pub fn MapFeatureState(props: MapFeatureStateProps) -> Element {
+    let handle_signal = try_use_map_handle_signal();
+    let mut applied_state = use_signal(|| None::<FeatureStateState>);
+
+    let desired_state = FeatureStateState {
+        feature: props.feature,
+        state: props.state,
+    };
+
+    // Props feed a tracked value so a changed `feature`/`state` reruns the effect
+    use_effect(use_reactive!(|desired_state| {
+        let Some(handle_signal) = handle_signal else {
+            return;
+        };
+        let Some(map) = handle_signal() else {
+            return;
+        };
+
+        let previous = applied_state.peek().clone();
+        if previous.as_ref() == Some(&desired_state) {
+            return;
+        }
+
+        if let Some(previous) = &previous {
+            if previous.feature == desired_state.feature {
+                let stale = previous.state.as_object().map(|keys| {
+                    keys.keys()
+                        .filter(|key| desired_state.state.get(key.as_str()).is_none())
+                        .cloned()
+                        .collect::<Vec<_>>()
+                });
+                for key in stale.unwrap_or_default() {
+                    map.remove_feature_state_key(&previous.feature, &key);
+                }
+            } else {
+                remove_applied_feature_state(&map, previous);
+            }
+        }
+
+        map.set_feature_state(&desired_state.feature, desired_state.state.clone());
+        applied_state.set(Some(desired_state));
+    }));
+
+    // Re-apply after a style switch, which rebuilds the feature's source
+    let map_state = use_map_state();
+    let mut style_was_loaded = use_signal(|| false);
+    use_effect(move || {
+        let style_loaded = map_state.read().is_style_loaded;
+        let reloaded = style_loaded && !*style_was_loaded.peek();
+        style_was_loaded.set(style_loaded);
+        if !reloaded {
+            return;
+        }
+        if let Some(handle_signal) = handle_signal
+            && let Some(map) = handle_signal.peek().clone()
+            && let Some(applied) = applied_state.peek().as_ref()
+        {
+            map.set_feature_state(&applied.feature, applied.state.clone());
+        }
+    });
+
+    use_drop(move || {
+        if let Some(handle_signal) = handle_signal
+            && let Some(map) = handle_signal.peek().clone()
+            && let Some(applied) = applied_state.peek().as_ref()
+        {
+            remove_applied_feature_state(&map, applied);
+        }
+    });
+
+    rsx! {}
+}
+
+/// Keep a map-wide setting (terrain, sky, fog) applied while mounted.
+///
+/// `apply` replaces the current value, so a prop change is a single call;
+/// `remove` runs on unmount.
+fn use_map_setting<T: Clone + PartialEq + 'static>(
+    options: &T,
+    apply: fn(&MapHandle, T),
+    remove: fn(&MapHandle),
+) {
+    let handle_signal = try_use_map_handle_signal();
+    let mut applied = use_signal(|| None::<T>);
+
+    use_effect(use_reactive(options, move |options| {
+        let Some(handle_signal) = handle_signal else {
+            return;
+        };
+        let Some(map) = handle_signal() else {
+            return;
+        };
+
+        if applied.peek().as_ref() == Some(&options) {
+            return;
+        }
+
+        apply(&map, options.clone());
+        applied.set(Some(options));
+    }));
+
+    use_drop(move || {
+        if let Some(handle_signal) = handle_signal
+            && let Some(map) = handle_signal.peek().clone()
+            && applied.peek().is_some()
+        {
+            remove(&map);
+        }
+    });
+}
+
+/// Declaratively enable 3D terrain and remove it on unmount.
+///
+/// The raster DEM source must already exist, e.g. from a sibling `MapSource`.
+#[derive(Props, Clone, PartialEq)]
+pub struct MapTerrainProps {
+    pub options: TerrainOptions,
+}
+
+#[component]
+pub fn MapTerrain(props: MapTerrainProps) -> Element {
+    use_map_setting(
+        &props.options,
+        MapHandle::set_terrain,
+        MapHandle::remove_terrain,
+    );
+    rsx! {}
+}
+
+/// Declaratively set the sky and remove it on unmount.
+#[derive(Props, Clone, PartialEq, Eq)]
+pub struct MapSkyProps {
+    pub options: SkyOptions,
+}
+
+#[component]
+pub fn MapSky(props: MapSkyProps) -> Element {
+    use_map_setting(&props.options, MapHandle::set_sky, MapHandle::remove_sky);
+    rsx! {}
+}
+
+/// Declaratively set fog/atmosphere and remove it on unmount.
+#[derive(Props, Clone, PartialEq, Eq)]
+pub struct MapFogProps {
+    pub options: FogOptions,
+}
+
+#[component]
+pub fn MapFog(props: MapFogProps) -> Element {
+    use_map_setting(&props.options, MapHandle::set_fog, MapHandle::remove_fog);
+    rsx! {}
+}
+
+/// Declaratively add an image to the map's sprite and remove it on unmount.
+///
+/// Reference it from symbol layers via `icon-image`. The image survives
+/// style switches.
+#[derive(Props, Clone, PartialEq)]
+pub struct MapImageProps {
+    pub id: String,
+    #[props(into)]
+    pub source: StyleImageSource,
+    #[props(default)]
+    pub options: StyleImageOptions,
+}
+
+#[component]
+pub fn MapImage(props: MapImageProps) -> Element {
+    let handle_signal = try_use_map_handle_signal();
+    let mut applied_image = use_signal(|| None::<ImageState>);
+
+    let desired_image = ImageState {
+        id: props.id,
+        source: props.source,
+        options: props.options,
+    };
+
+    use_effect(use_reactive!(|desired_image| {
+        let Some(handle_signal) = handle_signal else {
+            return;
+        };
+        let Some(map) = handle_signal() else {
+            return;
+        };
+
+        let previous = applied_image.peek().clone();
+        if previous.as_ref() == Some(&desired_image) {
+            return;
+        }
+        // Never record an image the map did not accept, or unmount would
+        // remove an image this component never added
+        if let Err(err) = desired_image.source.validate() {
+            tracing::error!(
+                map_id = map.map_id(),
+                image_id = %desired_image.id,
+                error = %err,
+                "Invalid style image"
+            );
+            return;
+        }
+
+        // `add_image` replaces an image with the same ID in place
+        if let Some(previous) = &previous
+            && previous.id != desired_image.id
+        {
+            map.remove_image(&previous.id);
+        }
+
+        map.add_image(
+            &desired_image.id,
+            desired_image.source.clone(),
+            desired_image.options.clone(),
+        );
+        applied_image.set(Some(desired_image));
+    }));
+
+    use_drop(move || {
+        if let Some(handle_signal) = handle_signal
+            && let Some(map) = handle_signal.peek().clone()
+            && let Some(image) = applied_image.peek().as_ref()
+        {
+            map.remove_image(&image.id);
+        }
+    });
+
+    rsx! {}
+}
@@ -1,43 +1,171 @@
 //! Typed event dispatch from JS bridge events to Dioxus handlers.
 
-use dioxus::prelude::{EventHandler, WritableExt};
+use dioxus::prelude::{EventHandler, ReadableExt, Signal, WritableExt};
 
 use crate::events::{
-    LayerClickEvent, LayerHoverEvent, MapClickEvent, MapContextMenuEvent, MapDblClickEvent,
-    MapErrorEvent, MapEvent, MapMoveEvent, MapPitchEvent, MapRotateEvent, MapZoomEvent,
-    MarkerClickEvent, MarkerDragEndEvent, MarkerDragStartEvent, MarkerHoverEvent,
+    LayerClickEvent, LayerHoverEvent, MapClickEvent, MapContextMenuEvent, MapDataEvent,
+    MapDblClickEvent, MapErrorEvent, MapEvent, MapGestureEvent, MapMouseEvent, MapMoveEvent,
+    MapPitchEvent, MapResizeEvent, MapRotateEvent, MapStyleImageMissingEvent, MapTouchEvent,
+    MapWheelEvent, MapZoomEvent, MarkerClickEvent, MarkerDragEndEvent, MarkerDragStartEvent,
+    MarkerHoverEvent, MovePhase,
 };
 use crate::handle::MapHandle;
+use crate::types::{MapPosition, MapState};
 
-use super::context::MapHandleSignal;
+use super::context::{LayerEventHandlers, LayerHandlerRegistry, MapHandleSignal, MapStateSignal};
 
+/// Two-way binding between a `viewport` signal and the live map camera.
+///
+/// `synced` holds the last position both sides agreed on: camera updates from
+/// the map are written to both signals, so the prop-driven effect only moves
+/// the map when the viewport diverges from it.
+#[derive(Clone, Copy)]
+pub struct ViewportSync {
+    pub(crate) viewport: Signal<MapPosition>,
+    pub(crate) synced: Signal<Option<MapPosition>>,
+}
+
+impl ViewportSync {
+    fn write_back(mut self, position: MapPosition) {
+        self.synced.set(Some(position));
+        if *self.viewport.peek() != position {
+            self.viewport.set(position);
+        }
+    }
+}
+
+/// The `Map` callbacks that bridge events are routed to.
+///
+/// With the `testing` feature, start from `MapEventHandlers::default()`, set
+/// the callbacks under test and feed it events through
+/// `testing::MockMap::dispatch`.
 #[derive(Clone, Default)]
-pub(crate) struct MapEventHandlers {
+pub struct MapEventHandlers {
+    /// Called when the map is ready with a `MapHandle`.
     pub on_ready: Option<EventHandler<MapHandle>>,
+    /// Called when the JS bridge reports an initialization or runtime error.
     pub on_error: Option<EventHandler<MapErrorEvent>>,
+    /// Called when the map is clicked.
     pub on_click: Option<EventHandler<MapClickEvent>>,
+    /// Called when the map is double-clicked.
     pub on_dblclick: Option<EventHandler<MapDblClickEvent>>,
+    /// Called on right-click / context menu.
     pub on_contextmenu: Option<EventHandler<MapContextMenuEvent>>,
+    /// Called when the mouse moves over the map (throttled).
+    pub on_mouse_move: Option<EventHandler<MapMouseEvent>>,
+    /// Called when a mouse button is pressed over the map.
+    pub on_mouse_down: Option<EventHandler<MapMouseEvent>>,
+    /// Called when a mouse button is released over the map.
+    pub on_mouse_up: Option<EventHandler<MapMouseEvent>>,
+    /// Called when the mouse leaves the map canvas.
+    pub on_mouse_out: Option<EventHandler<MapMouseEvent>>,
+    /// Called when a touch starts on the map.
+    pub on_touch_start: Option<EventHandler<MapTouchEvent>>,
+    /// Called when a touch moves on the map (throttled).
+    pub on_touch_move: Option<EventHandler<MapTouchEvent>>,
+    /// Called when a touch ends on the map.
+    pub on_touch_end: Option<EventHandler<MapTouchEvent>>,
+    /// Called when the wheel scrolls over the map (throttled, deltas summed).
+    pub on_wheel: Option<EventHandler<MapWheelEvent>>,
+    /// Called when a marker is clicked.
     pub on_marker_click: Option<EventHandler<MarkerClickEvent>>,
+    /// Called when hovering over a marker.
     pub on_marker_hover: Option<EventHandler<MarkerHoverEvent>>,
+    /// Called when a draggable marker starts being dragged.
     pub on_marker_dragstart: Option<EventHandler<MarkerDragStartEvent>>,
+    /// Called when a draggable marker is dropped after dragging.
     pub on_marker_dragend: Option<EventHandler<MarkerDragEndEvent>>,
+    /// Called when the map view changes (pan/zoom/rotate/pitch).
     pub on_move: Option<EventHandler<MapMoveEvent>>,
+    /// Called when a camera movement starts.
+    pub on_move_start: Option<EventHandler<MapGestureEvent>>,
+    /// Called when a zoom transition starts.
+    pub on_zoom_start: Option<EventHandler<MapGestureEvent>>,
+    /// Called when a rotation starts.
+    pub on_rotate_start: Option<EventHandler<MapGestureEvent>>,
+    /// Called when a pitch change starts.
+    pub on_pitch_start: Option<EventHandler<MapGestureEvent>>,
+    /// Called when the user starts dragging the map.
+    pub on_drag_start: Option<EventHandler<MapGestureEvent>>,
+    /// Called while the user drags the map (throttled).
+    pub on_drag: Option<EventHandler<MapGestureEvent>>,
+    /// Called when the user stops dragging the map.
+    pub on_drag_end: Option<EventHandler<MapGestureEvent>>,
+    /// Called when a shift-drag box zoom starts.
+    pub on_box_zoom_start: Option<EventHandler<MapGestureEvent>>,
+    /// Called when a box zoom ends.
+    pub on_box_zoom_end: Option<EventHandler<MapGestureEvent>>,
+    /// Called when zoom changes.
     pub on_zoom: Option<EventHandler<MapZoomEvent>>,
+    /// Called when bearing (rotation) changes.
     pub on_rotate: Option<EventHandler<MapRotateEvent>>,
+    /// Called when pitch changes.
     pub on_pitch: Option<EventHandler<MapPitchEvent>>,
+    /// Called when the map finishes rendering and no transitions or tile
+    /// loads are pending.
+    pub on_idle: Option<EventHandler<()>>,
+    /// Called when any map data (style or source) loads or changes.
+    pub on_data: Option<EventHandler<MapDataEvent>>,
+    /// Called when a source's data loads or changes, e.g. after a GeoJSON
+    /// update has been processed.
+    pub on_source_data: Option<EventHandler<MapDataEvent>>,
+    /// Called when the style loads or changes.
+    pub on_style_data: Option<EventHandler<MapDataEvent>>,
+    /// Called when any map data begins loading.
+    pub on_data_loading: Option<EventHandler<MapDataEvent>>,
+    /// Called when the map canvas is resized.
+    pub on_resize: Option<EventHandler<MapResizeEvent>>,
+    /// Called when the WebGL context is lost.
+    pub on_webgl_context_lost: Option<EventHandler<()>>,
+    /// Called when the style references an image that has not been added.
+    pub on_style_image_missing: Option<EventHandler<MapStyleImageMissingEvent>>,
+    /// Called when a feature in a layer is clicked.
     pub on_layer_click: Option<EventHandler<LayerClickEvent>>,
+    /// Called when a feature in a layer is double-clicked.
+    pub on_layer_dblclick: Option<EventHandler<LayerClickEvent>>,
+    /// Called when a feature in a layer is right-clicked.
+    pub on_layer_contextmenu: Option<EventHandler<LayerClickEvent>>,
+    /// Called when hovering over a feature in a layer.
     pub on_layer_hover: Option<EventHandler<LayerHoverEvent>>,
+    pub(crate) viewport: Option<ViewportSync>,
+    pub(crate) state: Option<MapStateSignal>,
+    pub(crate) layers: Option<LayerHandlerRegistry>,
 }
 
 impl MapEventHandlers {
-    pub fn dispatch(&self, map_id: &str, event: MapEvent, mut map_handle_signal: MapHandleSignal) {
+    /// Apply `update` to the tracked map state, notifying readers only on change.
+    fn update_state(&self, update: impl FnOnce(&mut MapState)) {
+        let Some(mut state) = self.state else {
+            return;
+        };
+        let mut next = *state.peek();
+        update(&mut next);
+        if *state.peek() != next {
+            state.set(next);
+        }
+    }
+
+    /// Handlers registered by the `MapLayer` for `layer_id`, if any.
+    fn layer(&self, layer_id: &str) -> LayerEventHandlers {
+        self.layers
+            .as_ref()
+            .and_then(|layers| layers.get(layer_id))
+            .unwrap_or_default()
+    }
+
+    /// Route one bridge event to its handler; `handle` is published on `Ready`.
+    pub fn dispatch(
+        &self,
+        handle: &MapHandle,
+        event: MapEvent,
+        mut map_handle_signal: MapHandleSignal,
+    ) {
+        self.track_state(&event);
         match event {
             MapEvent::Ready => {
-                let handle = MapHandle::new(map_id.to_string());
                 map_handle_signal.set(Some(handle.clone()));
                 if let Some(handler) = &self.on_ready {
-                    handler.call(handle);
+                    handler.call(handle.clone());
                 }
             }
             MapEvent::Click(event) => {
@@ -55,6 +183,46 @@ impl MapEventHandlers {
                     handler.call(event);
                 }
             }
+            MapEvent::MouseMove(event) => {
+                if let Some(handler) = &self.on_mouse_move {
+                    handler.call(event);
+                }
+            }
+            MapEvent::MouseDown(event) => {
+                if let Some(handler) = &self.on_mouse_down {
+                    handler.call(event);
+                }
+            }
+            MapEvent::MouseUp(event) => {
+                if let Some(handler) = &self.on_mouse_up {
+                    handler.call(event);
+                }
+            }
+            MapEvent::MouseOut(event) => {
+                if let Some(handler) = &self.on_mouse_out {
+                    handler.call(event);
+                }
+            }
+            MapEvent::TouchStart(event) => {
+                if let Some(handler) = &self.on_touch_start {
+                    handler.call(event);
+                }
+            }
+            MapEvent::TouchMove(event) => {
+                if let Some(handler) = &self.on_touch_move {
+                    handler.call(event);
+                }
+            }
+            MapEvent::TouchEnd(event) => {
+                if let Some(handler) = &self.on_touch_end {
+                    handler.call(event);
+                }
+            }
+            MapEvent::Wheel(event) => {
+                if let Some(handler) = &self.on_wheel {
+                    handler.call(event);
+                }
+            }
             MapEvent::MarkerClick(event) => {
                 if let Some(handler) = &self.on_marker_click {
                     handler.call(event);
@@ -76,10 +244,58 @@ impl MapEventHandlers {
                 }
             }
             MapEvent::Move(event) => {
+                if let Some(sync) = self.viewport {
+                    sync.write_back(event.position());
+                }
                 if let Some(handler) = &self.on_move {
                     handler.call(event);
                 }
             }
+            MapEvent::MoveStart(event) => {
+                if let Some(handler) = &self.on_move_start {
+                    handler.call(event);
+                }
+            }
+            MapEvent::ZoomStart(event) => {
+                if let Some(handler) = &self.on_zoom_start {
+                    handler.call(event);
+                }
+            }
+            MapEvent::RotateStart(event) => {
+                if let Some(handler) = &self.on_rotate_start {
+                    handler.call(event);
+                }
+            }
+            MapEvent::PitchStart(event) => {
+                if let Some(handler) = &self.on_pitch_start {
+                    handler.call(event);
+                }
+            }
+            MapEvent::DragStart(event) => {
+                if let Some(handler) = &self.on_drag_start {
+                    handler.call(event);
+                }
+            }
+            MapEvent::Drag(event) => {
+                if let Some(handler) = &self.on_drag {
+                    handler.call(event);
+                }
+            }
+            MapEvent::DragEnd(event) => {
+                if let Some(handler) = &self.on_drag_end {
+                    handler.call(event);
+                }
+            }
+            MapEvent::BoxZoomStart(event) => {
+                if let Some(handler) = &self.on_box_zoom_start {
+                    handler.call(event);
+                }
+            }
+            MapEvent::BoxZoomEnd(event) => {
+                if let Some(handler) = &self.on_box_zoom_end {
+                    handler.call(event);
+                }
+            }
             MapEvent::Zoom(event) => {
                 if let Some(handler) = &self.on_zoom {
                     handler.call(event);
@@ -96,25 +312,121 @@ impl MapEventHandlers {
                 }
             }
             MapEvent::LayerClick(event) => {
+                if let Some(handler) = self.layer(&event.layer_id).click {
+                    handler.call(event.clone());
+                }
                 if let Some(handler) = &self.on_layer_click {
                     handler.call(event);
                 }
             }
+            MapEvent::LayerDblClick(event) => {
+                if let Some(handler) = self.layer(&event.layer_id).dblclick {
+                    handler.call(event.clone());
+                }
+                if let Some(handler) = &self.on_layer_dblclick {
+                    handler.call(event);
+                }
+            }
+            MapEvent::LayerContextMenu(event) => {
+                if let Some(handler) = self.layer(&event.layer_id).context_menu {
+                    handler.call(event.clone());
+                }
+                if let Some(handler) = &self.on_layer_contextmenu {
+                    handler.call(event);
+                }
+            }
             MapEvent::LayerHover(event) => {
+                let layer = self.layer(&event.layer_id);
+                let per_layer = if event.is_leave() {
+                    layer.mouse_leave
+                } else {
+                    layer.hover
+                };
+                if let Some(handler) = per_layer {
+                    handler.call(event.clone());
+                }
                 if let Some(handler) = &self.on_layer_hover {
                     handler.call(event);
                 }
             }
+            MapEvent::Idle => {
+                if let Some(handler) = &self.on_idle {
+                    handler.call(());
+                }
+            }
+            MapEvent::Data(event) => {
+                if let Some(handler) = &self.on_data {
+                    handler.call(event);
+                }
+            }
+            MapEvent::SourceData(event) => {
+                if let Some(handler) = &self.on_source_data {
+                    handler.call(event);
+                }
+            }
+            MapEvent::StyleData(event) => {
+                if let Some(handler) = &self.on_style_data {
+                    handler.call(event);
+                }
+            }
+            MapEvent::DataLoading(event) => {
+                if let Some(handler) = &self.on_data_loading {
+                    handler.call(event);
+                }
+            }
+            MapEvent::Resize(event) => {
+                if let Some(handler) = &self.on_resize {
+                    handler.call(event);
+                }
+            }
+            MapEvent::WebGlContextLost => {
+                if let Some(handler) = &self.on_webgl_context_lost {
+                    handler.call(());
+                }
+            }
+            MapEvent::StyleImageMissing(event) => {
+                if let Some(handler) = &self.on_style_image_missing {
+                    handler.call(event);
+                }
+            }
+            // Only feeds the tracked map state.
+            MapEvent::LoadState(_) => {}
             MapEvent::Error(event) => {
                 if let Some(handler) = &self.on_error {
                     handler.call(event.clone());
                 }
                 tracing::error!(
-                    map_id,
+                    map_id = handle.map_id(),
                     message = ?event.message,
                     "MapLibre bridge error event"
                 );
             }
         }
     }
+
+    fn track_state(&self, event: &MapEvent) {
+        match event {
+            MapEvent::Ready => self.update_state(|state| state.is_style_loaded = true),
+            MapEvent::LoadState(event) => self.update_state(|state| {
+                state.is_style_loaded = event.style_loaded;
+                state.tiles_loaded = event.tiles_loaded;
+            }),
+            MapEvent::Move(event) => self.update_state(|state| {
+                state.center = event.center;
+                state.zoom = event.zoom;
+                state.bearing = event.bearing;
+                state.pitch = event.pitch;
+                if event.bounds.is_some() {
+                    state.bounds = event.bounds;
+                }
+                state.padding = event.padding;
+                state.is_moving = event.phase == MovePhase::Move;
+            }),
+            MapEvent::MoveStart(_) => self.update_state(|state| state.is_moving = true),
+            MapEvent::Zoom(event) => self.update_state(|state| state.zoom = event.zoom),
+            MapEvent::Rotate(event) => self.update_state(|state| state.bearing = event.bearing),
+            MapEvent::Pitch(event) => self.update_state(|state| state.pitch = event.pitch),
+            _ => {}
+        }
+    }
 }
@@ -2,26 +2,33 @@
 
 use dioxus::prelude::*;
 
-use super::context::MapHandleSignal;
+use super::context::{MapHandleSignal, MapStateSignal};
 #[cfg(target_arch = "wasm32")]
 use super::event_dispatch::MapEventHandlers;
 #[cfg(target_arch = "wasm32")]
 use crate::events::MapEvent;
 use crate::events::{
-    LayerClickEvent, LayerHoverEvent, MapClickEvent, MapContextMenuEvent, MapDblClickEvent,
-    MapErrorEvent, MapMoveEvent, MapPitchEvent, MapRotateEvent, MapZoomEvent, MarkerClickEvent,
-    MarkerDragEndEvent, MarkerDragStartEvent, MarkerHoverEvent,
+    LayerClickEvent, LayerHoverEvent, MapClickEvent, MapContextMenuEvent, MapDataEvent,
+    MapDblClickEvent, MapErrorEvent, MapGestureEvent, MapMouseEvent, MapMoveEvent, MapPitchEvent,
+    MapResizeEvent, MapRotateEvent, MapStyleImageMissingEvent, MapTouchEvent, MapWheelEvent,
+    MapZoomEvent, MarkerClickEvent, MarkerDragEndEvent, MarkerDragStartEvent, MarkerHoverEvent,
 };
 use crate::handle::MapHandle;
 use crate::interop::generate_map_id;
-use crate::types::{Bounds, LatLng};
+use crate::options::{MapStyle, SetStyleOptions};
+use crate::types::{Bounds, LatLng, MapPosition, MapState};
 
 /// Props for the `Map` component.
 #[derive(Props, Clone, PartialEq)]
 pub struct MapProps {
-    /// MapLibre style URL.
-    #[props(default = "https://basemaps.cartocdn.com/gl/dark-matter-gl-style/style.json".to_string())]
-    pub style: String,
+    /// MapLibre style: a style URL or an inline `StyleSpecification`.
+    #[props(into, default)]
+    pub style: MapStyle,
+
+    /// How later `style` changes are applied, e.g. `diff: true` to patch
+    /// only what changed between two similar basemaps.
+    #[props(default)]
+    pub style_options: SetStyleOptions,
 
     /// Initial center coordinate.
     #[props(default = LatLng::helsinki())]
@@ -39,6 +46,20 @@ pub struct MapProps {
     #[props(default = 0.0)]
     pub pitch: f64,
 
+    /// Controlled camera position.
+    ///
+    /// When set, the map starts at this position (ignoring `center`, `zoom`,
+    /// `bearing` and `pitch`), jumps to it whenever the signal changes, and
+    /// writes user-driven camera changes back into it. The signal is bound
+    /// once at mount.
+    #[props(optional)]
+    pub viewport: Option<Signal<MapPosition>>,
+
+    /// Animation duration in milliseconds for controlled `viewport` changes
+    /// (0 = jump without animation).
+    #[props(default = 0)]
+    pub viewport_transition_ms: u32,
+
     /// Minimum zoom level.
     #[props(optional)]
     pub min_zoom: Option<f64>,
@@ -63,7 +84,8 @@ pub struct MapProps {
     #[props(default = "100%".to_string())]
     pub width: String,
 
-    /// Throttle for `on_move` updates in milliseconds (0 = every animation frame).
+    /// Throttle for `on_move`, `on_mouse_move`, `on_touch_move` and `on_wheel`
+    /// updates in milliseconds (0 = every animation frame).
     #[props(default = 80)]
     pub move_event_throttle_ms: u32,
 
@@ -87,6 +109,38 @@ pub struct MapProps {
     #[props(optional)]
     pub on_contextmenu: Option<EventHandler<MapContextMenuEvent>>,
 
+    /// Called when the mouse moves over the map (throttled).
+    #[props(optional)]
+    pub on_mouse_move: Option<EventHandler<MapMouseEvent>>,
+
+    /// Called when a mouse button is pressed over the map.
+    #[props(optional)]
+    pub on_mouse_down: Option<EventHandler<MapMouseEvent>>,
+
+    /// Called when a mouse button is released over the map.
+    #[props(optional)]
+    pub on_mouse_up: Option<EventHandler<MapMouseEvent>>,
+
+    /// Called when the mouse leaves the map canvas.
+    #[props(optional)]
+    pub on_mouse_out: Option<EventHandler<MapMouseEvent>>,
+
+    /// Called when a touch starts on the map.
+    #[props(optional)]
+    pub on_touch_start: Option<EventHandler<MapTouchEvent>>,
+
+    /// Called when a touch moves on the map (throttled).
+    #[props(optional)]
+    pub on_touch_move: Option<EventHandler<MapTouchEvent>>,
+
+    /// Called when a touch ends on the map.
+    #[props(optional)]
+    pub on_touch_end: Option<EventHandler<MapTouchEvent>>,
+
+    /// Called when the wheel scrolls over the map (throttled, deltas summed).
+    #[props(optional)]
+    pub on_wheel: Option<EventHandler<MapWheelEvent>>,
+
     /// Called when a marker is clicked.
     #[props(optional)]
     pub on_marker_click: Option<EventHandler<MarkerClickEvent>>,
@@ -107,6 +161,42 @@ pub struct MapProps {
     #[props(optional)]
     pub on_move: Option<EventHandler<MapMoveEvent>>,
 
+    /// Called when a camera movement starts.
+    #[props(optional)]
+    pub on_move_start: Option<EventHandler<MapGestureEvent>>,
+
+    /// Called when a zoom transition starts.
+    #[props(optional)]
+    pub on_zoom_start: Option<EventHandler<MapGestureEvent>>,
+
+    /// Called when a rotation starts.
+    #[props(optional)]
+    pub on_rotate_start: Option<EventHandler<MapGestureEvent>>,
+
+    /// Called when a pitch change starts.
+    #[props(optional)]
+    pub on_pitch_start: Option<EventHandler<MapGestureEvent>>,
+
+    /// Called when the user starts dragging the map.
+    #[props(optional)]
+    pub on_drag_start: Option<EventHandler<MapGestureEvent>>,
+
+    /// Called while the user drags the map (throttled).
+    #[props(optional)]
+    pub on_drag: Option<EventHandler<MapGestureEvent>>,
+
+    /// Called when the user stops dragging the map.
+    #[props(optional)]
+    pub on_drag_end: Option<EventHandler<MapGestureEvent>>,
+
+    /// Called when a shift-drag box zoom starts.
+    #[props(optional)]
+    pub on_box_zoom_start: Option<EventHandler<MapGestureEvent>>,
+
+    /// Called when a box zoom ends.
+    #[props(optional)]
+    pub on_box_zoom_end: Option<EventHandler<MapGestureEvent>>,
+
     /// Called when zoom changes.
     #[props(optional)]
     pub on_zoom: Option<EventHandler<MapZoomEvent>>,
@@ -119,10 +209,52 @@ pub struct MapProps {
     #[props(optional)]
     pub on_pitch: Option<EventHandler<MapPitchEvent>>,
 
+    /// Called when the map finishes rendering and no transitions or tile
+    /// loads are pending.
+    #[props(optional)]
+    pub on_idle: Option<EventHandler<()>>,
+
+    /// Called when any map data (style or source) loads or changes.
+    #[props(optional)]
+    pub on_data: Option<EventHandler<MapDataEvent>>,
+
+    /// Called when a source's data loads or changes, e.g. after a GeoJSON
+    /// update has been processed.
+    #[props(optional)]
+    pub on_source_data: Option<EventHandler<MapDataEvent>>,
+
+    /// Called when the style loads or changes.
+    #[props(optional)]
+    pub on_style_data: Option<EventHandler<MapDataEvent>>,
+
+    /// Called when any map data begins loading.
+    #[props(optional)]
+    pub on_data_loading: Option<EventHandler<MapDataEvent>>,
+
+    /// Called when the map canvas is resized.
+    #[props(optional)]
+    pub on_resize: Option<EventHandler<MapResizeEvent>>,
+
+    /// Called when the WebGL context is lost.
+    #[props(optional)]
+    pub on_webgl_context_lost: Option<EventHandler<()>>,
+
+    /// Called when the style references an image that has not been added.
+    #[props(optional)]
+    pub on_style_image_missing: Option<EventHandler<MapStyleImageMissingEvent>>,
+
     /// Called when a feature in a layer is clicked.
     #[props(optional)]
     pub on_layer_click: Option<EventHandler<LayerClickEvent>>,
 
+    /// Called when a feature in a layer is double-clicked.
+    #[props(optional)]
+    pub on_layer_dblclick: Option<EventHandler<LayerClickEvent>>,
+
+    /// Called when a feature in a layer is right-clicked.
+    #[props(optional)]
+    pub on_layer_contextmenu: Option<EventHandler<LayerClickEvent>>,
+
     /// Called when hovering over a feature in a layer.
     #[props(optional)]
     pub on_layer_hover: Option<EventHandler<LayerHoverEvent>>,
@@ -144,9 +276,35 @@ pub fn Map(props: MapProps) -> Element {
     let mut map_handle_signal: MapHandleSignal = use_signal(|| None::<MapHandle>);
     use_context_provider(|| map_handle_signal);
 
+    let viewport = use_hook(|| props.viewport);
+    let initial = viewport.map_or_else(
+        || MapPosition {
+            center: props.center,
+            zoom: props.zoom,
+            bearing: props.bearing,
+            pitch: props.pitch,
+        },
+        |signal| *signal.peek(),
+    );
+
+    let map_state_signal: MapStateSignal = use_signal(|| MapState::from(initial));
+    use_context_provider(|| map_state_signal);
+
     #[cfg(target_arch = "wasm32")]
     {
-        use crate::interop::{destroy_map_js, init_map_js, set_move_event_throttle_js};
+        use super::context::LayerHandlerRegistry;
+        use super::event_dispatch::ViewportSync;
+        use crate::interop::{
+            destroy_map_js, init_map_js, set_move_event_throttle_js, set_opt_in_events_js,
+        };
+        use crate::options::{EaseToOptions, JumpToOptions};
+
+        let layer_handlers = use_context_provider(LayerHandlerRegistry::default);
+        let viewport_synced = use_signal(|| viewport.map(|signal| *signal.peek()));
+        let viewport_sync = viewport.map(|viewport| ViewportSync {
+            viewport,
+            synced: viewport_synced,
+        });
 
         let handlers = MapEventHandlers {
             on_ready: props.on_ready,
@@ -154,33 +312,66 @@ pub fn Map(props: MapProps) -> Element {
             on_click: props.on_click,
             on_dblclick: props.on_dblclick,
             on_contextmenu: props.on_contextmenu,
+            on_mouse_move: props.on_mouse_move,
+            on_mouse_down: props.on_mouse_down,
+            on_mouse_up: props.on_mouse_up,
+            on_mouse_out: props.on_mouse_out,
+            on_touch_start: props.on_touch_start,
+            on_touch_move: props.on_touch_move,
+            on_touch_end: props.on_touch_end,
+            on_wheel: props.on_wheel,
             on_marker_click: props.on_marker_click,
             on_marker_hover: props.on_marker_hover,
             on_marker_dragstart: props.on_marker_dragstart,
             on_marker_dragend: props.on_marker_dragend,
             on_move: props.on_move,
+            on_move_start: props.on_move_start,
+            on_zoom_start: props.on_zoom_start,
+            on_rotate_start: props.on_rotate_start,
+            on_pitch_start: props.on_pitch_start,
+            on_drag_start: props.on_drag_start,
+            on_drag: props.on_drag,
+            on_drag_end: props.on_drag_end,
+            on_box_zoom_start: props.on_box_zoom_start,
+            on_box_zoom_end: props.on_box_zoom_end,
             on_zoom: props.on_zoom,
             on_rotate: props.on_rotate,
             on_pitch: props.on_pitch,
+            on_idle: props.on_idle,
+            on_data: props.on_data,
+            on_source_data: props.on_source_data,
+            on_style_data: props.on_style_data,
+            on_data_loading: props.on_data_loading,
+            on_resize: props.on_resize,
+            on_webgl_context_lost: props.on_webgl_context_lost,
+            on_style_image_missing: props.on_style_image_missing,
             on_layer_click: props.on_layer_click,
+            on_layer_dblclick: props.on_layer_dblclick,
+            on_layer_contextmenu: props.on_layer_contextmenu,
             on_layer_hover: props.on_layer_hover,
+            viewport: viewport_sync,
+            state: Some(map_state_signal),
+            layers: Some(layer_handlers),
         };
 
         let style = props.style.clone();
-        let center = props.center;
-        let zoom = props.zoom;
-        let bearing = props.bearing;
-        let pitch = props.pitch;
+        let MapPosition {
+            center,
+            zoom,
+            bearing,
+            pitch,
+        } = initial;
         let min_zoom = props.min_zoom;
         let max_zoom = props.max_zoom;
         let max_bounds = props.max_bounds;
         let cooperative_gestures = props.cooperative_gestures;
         let move_event_throttle_ms = props.move_event_throttle_ms;
+        let opt_in_events = opt_in_event_types(&props);
 
         {
             let map_id = map_id.clone();
             let container_id = container_id.clone();
-            let handlers = handlers.clone();
+            let opt_in_events = opt_in_events.clone();
 
             use_effect(move || {
                 if init_started() {
@@ -193,6 +384,7 @@ pub fn Map(props: MapProps) -> Element {
                 let style = style.clone();
                 let handlers = handlers.clone();
                 let map_handle_signal = map_handle_signal;
+                let opt_in_events = opt_in_events.clone();
 
                 let max_bounds_str = max_bounds.map(|b| {
                     format!(
@@ -216,13 +408,20 @@ pub fn Map(props: MapProps) -> Element {
                         max_bounds_str.as_deref(),
                         cooperative_gestures,
                         move_event_throttle_ms,
+                        &opt_in_events,
                     );
 
                     let mut eval = document::eval(&init_js);
+                    let handle = MapHandle::new(map_id);
 
                     while let Ok(json) = eval.recv::<String>().await {
-                        if let Ok(event) = serde_json::from_str::<MapEvent>(&json) {
-                            handlers.dispatch(&map_id, event, map_handle_signal);
+                        match serde_json::from_str::<MapEvent>(&json) {
+                            Ok(event) => handlers.dispatch(&handle, event, map_handle_signal),
+                            Err(err) => tracing::warn!(
+                                map_id = handle.map_id(),
+                                error = %err,
+                                "Failed to decode MapLibre bridge event"
+                            ),
                         }
                     }
                 });
@@ -233,16 +432,58 @@ pub fn Map(props: MapProps) -> Element {
         {
             let mut tracked_style = use_signal(|| props.style.clone());
             if tracked_style() != props.style && init_started() {
-                let map_id = map_id.clone();
-                let new_style = props.style.clone();
-                tracked_style.set(new_style.clone());
-                spawn(async move {
-                    let js = crate::interop::set_style_js(&map_id, &new_style);
-                    let _ = document::eval(&js).await;
-                });
+                tracked_style.set(props.style.clone());
+                let map = map_handle_signal
+                    .peek()
+                    .clone()
+                    .unwrap_or_else(|| MapHandle::new(map_id.clone()));
+                map.set_style_with(props.style.clone(), props.style_options.clone());
             }
         }
 
+        // Controlled viewport: move the camera when the signal diverges from the
+        // last position synced with the map.
+        {
+            let mut tracked_transition = use_signal(|| props.viewport_transition_ms);
+            if *tracked_transition.peek() != props.viewport_transition_ms {
+                tracked_transition.set(props.viewport_transition_ms);
+            }
+
+            use_effect(move || {
+                let Some(mut sync) = viewport_sync else {
+                    return;
+                };
+                let desired = (sync.viewport)();
+                let Some(map) = map_handle_signal() else {
+                    return;
+                };
+                if *sync.synced.peek() == Some(desired) {
+                    return;
+                }
+                sync.synced.set(Some(desired));
+
+                let duration = *tracked_transition.peek();
+                if duration == 0 {
+                    map.jump_to(JumpToOptions {
+                        center: Some(desired.center),
+                        zoom: Some(desired.zoom),
+                        bearing: Some(desired.bearing),
+                        pitch: Some(desired.pitch),
+                        ..Default::default()
+                    });
+                } else {
+                    map.ease_to(EaseToOptions {
+                        center: Some(desired.center),
+                        zoom: Some(desired.zoom),
+                        bearing: Some(desired.bearing),
+                        pitch: Some(desired.pitch),
+                        duration: Some(duration),
+                        ..Default::default()
+                    });
+                }
+            });
+        }
+
         // Live move throttle switching: detect prop changes after initialization.
         {
             let mut tracked_move_throttle = use_signal(|| props.move_event_throttle_ms);
@@ -257,17 +498,27 @@ pub fn Map(props: MapProps) -> Element {
             }
         }
 
-        // Cleanup on unmount.
+        // Live event opt-in: forward only the high-volume types with a handler.
         {
-            let map_id = map_id.clone();
-            use_drop(move || {
-                map_handle_signal.set(None);
-                let cleanup_js = destroy_map_js(&map_id);
+            let mut tracked_opt_in_events = use_signal(|| opt_in_events.clone());
+            if tracked_opt_in_events() != opt_in_events && init_started() {
+                let map_id = map_id.clone();
+                tracked_opt_in_events.set(opt_in_events.clone());
                 spawn(async move {
-                    let _ = document::eval(&cleanup_js).await;
+                    let js = set_opt_in_events_js(&map_id, &opt_in_events);
+                    let _ = document::eval(&js).await;
                 });
-            });
+            }
         }
+
+        // Cleanup on unmount.
+        use_drop(move || {
+            map_handle_signal.set(None);
+            let cleanup_js = destroy_map_js(&map_id);
+            spawn(async move {
+                let _ = document::eval(&cleanup_js).await;
+            });
+        });
     }
 
     rsx! {
@@ -279,3 +530,38 @@ pub fn Map(props: MapProps) -> Element {
         }
     }
 }
+
+/// Bridge event types for the opt-in handlers set on `props`.
+#[cfg(target_arch = "wasm32")]
+fn opt_in_event_types(props: &MapProps) -> Vec<&'static str> {
+    [
+        ("mousemove", props.on_mouse_move.is_some()),
+        ("mousedown", props.on_mouse_down.is_some()),
+        ("mouseup", props.on_mouse_up.is_some()),
+        ("mouseout", props.on_mouse_out.is_some()),
+        ("touchstart", props.on_touch_start.is_some()),
+        ("touchmove", props.on_touch_move.is_some()),
+        ("touchend", props.on_touch_end.is_some()),
+        ("wheel", props.on_wheel.is_some()),
+        ("idle", props.on_idle.is_some()),
+        ("data", props.on_data.is_some()),
+        ("sourcedata", props.on_source_data.is_some()),
+        ("styledata", props.on_style_data.is_some()),
+        ("dataloading", props.on_data_loading.is_some()),
+        ("resize", props.on_resize.is_some()),
+        ("webglcontextlost", props.on_webgl_context_lost.is_some()),
+        ("styleimagemissing", props.on_style_image_missing.is_some()),
+        ("movestart", props.on_move_start.is_some()),
+        ("zoomstart", props.on_zoom_start.is_some()),
+        ("rotatestart", props.on_rotate_start.is_some()),
+        ("pitchstart", props.on_pitch_start.is_some()),
+        ("dragstart", props.on_drag_start.is_some()),
+        ("drag", props.on_drag.is_some()),
+        ("dragend", props.on_drag_end.is_some()),
+        ("boxzoomstart", props.on_box_zoom_start.is_some()),
+        ("boxzoomend", props.on_box_zoom_end.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(event_type, enabled)| enabled.then_some(event_type))
+    .collect()
+}
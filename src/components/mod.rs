@@ -2,12 +2,18 @@
 
 mod context;
 mod declarative;
-#[cfg(target_arch = "wasm32")]
+#[cfg(any(target_arch = "wasm32", feature = "testing"))]
 mod event_dispatch;
 pub mod map;
 
-pub use context::use_map_handle;
+pub use context::{use_map_handle, use_map_state};
 pub use declarative::{
-    MapControl, MapControlKind, MapLayer, MapMarker, MapPopup, MapSource, MapSourceKind,
+    MapControl, MapControlKind, MapFeatureState, MapFog, MapImage, MapLayer, MapMarker, MapPopup,
+    MapSky, MapSource, MapSourceKind, MapTerrain,
 };
 pub use map::Map;
+
+#[cfg(feature = "testing")]
+pub(crate) use context::{LayerHandlerRegistry, MapHandleSignal, MapStateSignal};
+#[cfg(feature = "testing")]
+pub use event_dispatch::MapEventHandlers;
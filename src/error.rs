@@ -0,0 +1,75 @@
+//! Error types for fallible map operations.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Result alias for fallible `MapHandle` operations (`try_*` methods).
+pub type MapResult<T> = Result<T, MapError>;
+
+/// Error reported by a fallible `MapHandle` operation.
+///
+/// See the `MapHandle` docs for which operations have `try_*` variants.
+///
+/// The JS bridge reports these as `{ kind, ... }` objects, so the enum is
+/// internally tagged by `kind`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MapError {
+    /// No map is registered under the handle's map ID.
+    MapNotFound { map_id: String },
+    /// A source with this ID already exists.
+    SourceExists { id: String },
+    /// The referenced source does not exist.
+    SourceMissing { id: String },
+    /// A layer with this ID already exists.
+    LayerExists { id: String },
+    /// The referenced layer does not exist.
+    LayerMissing { id: String },
+    /// The referenced marker does not exist.
+    MarkerMissing { id: String },
+    /// An image with this ID already exists in the style.
+    ImageExists { id: String },
+    /// MapLibre rejected the input while validating it against the style spec.
+    InvalidStyleSpec { message: String },
+    /// MapLibre threw while performing the operation.
+    JsException { message: String },
+    /// The JS bridge could not be reached or returned an unreadable result.
+    Bridge { message: String },
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MapNotFound { map_id } => write!(f, "map not found: {map_id}"),
+            Self::SourceExists { id } => write!(f, "source already exists: {id}"),
+            Self::SourceMissing { id } => write!(f, "source not found: {id}"),
+            Self::LayerExists { id } => write!(f, "layer already exists: {id}"),
+            Self::LayerMissing { id } => write!(f, "layer not found: {id}"),
+            Self::MarkerMissing { id } => write!(f, "marker not found: {id}"),
+            Self::ImageExists { id } => write!(f, "image already exists: {id}"),
+            Self::InvalidStyleSpec { message } => write!(f, "invalid style spec: {message}"),
+            Self::JsException { message } => write!(f, "JS exception: {message}"),
+            Self::Bridge { message } => write!(f, "JS bridge error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for MapError {}
+
+/// Envelope returned by fallible bridge snippets (`{ error }` or `{ value }`).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum BridgeResult<T> {
+    Err { error: MapError },
+    Ok { value: T },
+}
+
+impl<T> BridgeResult<T> {
+    pub(crate) fn into_result(self) -> MapResult<T> {
+        match self {
+            Self::Ok { value } => Ok(value),
+            Self::Err { error } => Err(error),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Event types for map interactions
 
-use crate::types::{Bounds, LatLng, Point};
+use crate::options::Padding;
+use crate::types::{Bounds, FeatureId, LatLng, MapPosition, Point, QueryFeature};
 use serde::{Deserialize, Serialize};
 
 /// Event fired when the map is clicked
@@ -30,6 +31,86 @@ pub struct MapContextMenuEvent {
     pub point: Point,
 }
 
+/// Keyboard modifiers held during a pointer event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ModifierKeys {
+    /// Alt / Option key
+    #[serde(default)]
+    pub alt: bool,
+    /// Control key
+    #[serde(default)]
+    pub ctrl: bool,
+    /// Meta / Command / Windows key
+    #[serde(default)]
+    pub meta: bool,
+    /// Shift key
+    #[serde(default)]
+    pub shift: bool,
+}
+
+impl ModifierKeys {
+    /// Whether any modifier key is held
+    pub fn any(&self) -> bool {
+        self.alt || self.ctrl || self.meta || self.shift
+    }
+}
+
+/// Event fired for mouse movement and buttons over the map
+/// (`mousemove`, `mousedown`, `mouseup`, `mouseout`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapMouseEvent {
+    /// Geographic coordinates under the cursor
+    pub latlng: LatLng,
+    /// Screen pixel coordinates relative to the map container
+    pub point: Point,
+    /// DOM `MouseEvent.button` (0 = primary, 1 = middle, 2 = secondary)
+    #[serde(default)]
+    pub button: i16,
+    /// Modifier keys held during the event
+    #[serde(default)]
+    pub modifiers: ModifierKeys,
+}
+
+/// Event fired for touches on the map (`touchstart`, `touchmove`, `touchend`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapTouchEvent {
+    /// Geographic coordinates of the touch centroid
+    pub latlng: LatLng,
+    /// Screen pixel coordinates of the touch centroid
+    pub point: Point,
+    /// Geographic coordinates of each touch point
+    #[serde(default)]
+    pub latlngs: Vec<LatLng>,
+    /// Screen pixel coordinates of each touch point
+    #[serde(default)]
+    pub points: Vec<Point>,
+    /// Modifier keys held during the event
+    #[serde(default)]
+    pub modifiers: ModifierKeys,
+}
+
+/// Event fired when the mouse wheel or trackpad scrolls over the map
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapWheelEvent {
+    /// Geographic coordinates under the cursor
+    pub latlng: LatLng,
+    /// Screen pixel coordinates relative to the map container
+    pub point: Point,
+    /// Horizontal scroll amount, summed over throttled events
+    #[serde(default)]
+    pub delta_x: f64,
+    /// Vertical scroll amount, summed over throttled events
+    #[serde(default)]
+    pub delta_y: f64,
+    /// DOM `WheelEvent.deltaMode` (0 = pixels, 1 = lines, 2 = pages)
+    #[serde(default)]
+    pub delta_mode: u32,
+    /// Modifier keys held during the event
+    #[serde(default)]
+    pub modifiers: ModifierKeys,
+}
+
 /// Event fired when a marker is clicked
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarkerClickEvent {
@@ -72,19 +153,85 @@ pub struct MarkerDragEndEvent {
     pub latlng: LatLng,
 }
 
+/// Which part of a camera movement a `MapMoveEvent` reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MovePhase {
+    /// The camera is moving (throttled by `move_event_throttle_ms`)
+    #[default]
+    #[serde(rename = "move")]
+    Move,
+    /// The camera came to rest
+    #[serde(rename = "moveend")]
+    MoveEnd,
+    /// Initial camera, sent once when the map has loaded
+    #[serde(rename = "move_load")]
+    Load,
+}
+
 /// Event fired when the map view changes (pan/zoom)
+///
+/// Carries a full camera snapshot. `seq` increases with every move event from
+/// the same map, so handlers doing async work can drop stale results.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MapMoveEvent {
     /// New center position
     pub center: LatLng,
     /// New zoom level
     pub zoom: f64,
+    /// New bearing in degrees
+    #[serde(default)]
+    pub bearing: f64,
+    /// New pitch in degrees
+    #[serde(default)]
+    pub pitch: f64,
+    /// Current viewport padding
+    #[serde(default)]
+    pub padding: Padding,
     /// Current viewport bounds (sw/ne corners)
     #[serde(default)]
     pub bounds: Option<Bounds>,
-    /// Move phase from bridge (`move`, `moveend`, `move_load`)
+    /// Movement phase
+    #[serde(default)]
+    pub phase: MovePhase,
+    /// Per-map sequence number, starting at 1
     #[serde(default)]
-    pub phase: Option<String>,
+    pub seq: u64,
+    /// Whether the latest change comes from user input rather than a
+    /// `MapHandle` call such as `fly_to`
+    #[serde(default)]
+    pub user_initiated: bool,
+}
+
+impl MapMoveEvent {
+    /// Center, zoom, bearing and pitch of the snapshot
+    pub fn position(&self) -> MapPosition {
+        MapPosition {
+            center: self.center,
+            zoom: self.zoom,
+            bearing: self.bearing,
+            pitch: self.pitch,
+        }
+    }
+}
+
+/// Event fired when a camera gesture or transition starts or ends
+/// (`movestart`, `zoomstart`, `rotatestart`, `pitchstart`, `dragstart`, `drag`,
+/// `dragend`, `boxzoomstart`, `boxzoomend`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapGestureEvent {
+    /// Whether the change comes from user input rather than a `MapHandle`
+    /// call such as `fly_to`
+    pub user_initiated: bool,
+    /// Center at the time of the event
+    pub center: LatLng,
+    /// Zoom level at the time of the event
+    pub zoom: f64,
+    /// Bearing in degrees at the time of the event
+    #[serde(default)]
+    pub bearing: f64,
+    /// Pitch in degrees at the time of the event
+    #[serde(default)]
+    pub pitch: f64,
 }
 
 /// Event fired when the zoom level changes
@@ -92,6 +239,10 @@ pub struct MapMoveEvent {
 pub struct MapZoomEvent {
     /// New zoom level
     pub zoom: f64,
+    /// Whether the change comes from user input rather than a `MapHandle`
+    /// call such as `zoom_to`
+    #[serde(default)]
+    pub user_initiated: bool,
 }
 
 /// Event fired when the bearing (rotation) changes
@@ -99,6 +250,10 @@ pub struct MapZoomEvent {
 pub struct MapRotateEvent {
     /// New bearing in degrees
     pub bearing: f64,
+    /// Whether the change comes from user input rather than a `MapHandle`
+    /// call such as `rotate_to`
+    #[serde(default)]
+    pub user_initiated: bool,
 }
 
 /// Event fired when the pitch changes
@@ -106,19 +261,51 @@ pub struct MapRotateEvent {
 pub struct MapPitchEvent {
     /// New pitch in degrees
     pub pitch: f64,
+    /// Whether the change comes from user input rather than a `MapHandle`
+    /// call such as `set_pitch`
+    #[serde(default)]
+    pub user_initiated: bool,
 }
 
-/// Event fired when a feature in a layer is clicked
+/// Event fired when a feature in a layer is clicked, double-clicked or
+/// right-clicked
+///
+/// `feature_id` and `properties` describe the topmost feature; `features`
+/// holds every feature of the layer under the cursor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LayerClickEvent {
     /// Layer ID where the click occurred
     pub layer_id: String,
-    /// GeoJSON feature ID (numeric, if present)
-    pub feature_id: Option<i64>,
+    /// Feature ID, numeric or string (e.g. promoted with `promoteId`), if
+    /// present
+    pub feature_id: Option<FeatureId>,
     /// Feature properties from GeoJSON
     pub properties: serde_json::Value,
     /// Geographic coordinates of the click
     pub latlng: LatLng,
+    /// Screen pixel coordinates relative to the map container
+    #[serde(default)]
+    pub point: Point,
+    /// Modifier keys held during the click
+    #[serde(default)]
+    pub modifiers: ModifierKeys,
+    /// All hit features in this layer, topmost first. Features that fail to
+    /// decode are skipped.
+    #[serde(default, deserialize_with = "skip_invalid_features")]
+    pub features: Vec<QueryFeature>,
+}
+
+/// Which hover transition a `LayerHoverEvent` reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LayerHoverPhase {
+    /// The cursor moved onto the layer
+    #[default]
+    Enter,
+    /// The cursor moved to a different feature of the layer
+    Change,
+    /// The cursor left the layer
+    Leave,
 }
 
 /// Event fired when hovering over a feature in a layer
@@ -126,8 +313,12 @@ pub struct LayerClickEvent {
 pub struct LayerHoverEvent {
     /// Layer ID where the hover occurred
     pub layer_id: String,
-    /// GeoJSON feature ID (numeric, if present). None when mouse leaves.
-    pub feature_id: Option<i64>,
+    /// Enter, change or leave
+    #[serde(default)]
+    pub phase: LayerHoverPhase,
+    /// Feature ID, numeric or string (e.g. promoted with `promoteId`), if
+    /// present. None when mouse leaves.
+    pub feature_id: Option<FeatureId>,
     /// Feature properties from GeoJSON. None when mouse leaves.
     pub properties: Option<serde_json::Value>,
     /// Geographic coordinates
@@ -136,6 +327,39 @@ pub struct LayerHoverEvent {
     pub cursor_x: f64,
     /// Cursor Y position (screen coordinates)
     pub cursor_y: f64,
+    /// Screen pixel coordinates relative to the map container
+    #[serde(default)]
+    pub point: Point,
+    /// Modifier keys held during the event
+    #[serde(default)]
+    pub modifiers: ModifierKeys,
+    /// All hovered features in this layer, topmost first. Empty when mouse leaves.
+    ///
+    /// Geometry is not sent for hover events, so `geometry` is `None`; query
+    /// the feature if you need it. Features that fail to decode are skipped.
+    #[serde(default, deserialize_with = "skip_invalid_features")]
+    pub features: Vec<QueryFeature>,
+}
+
+impl LayerHoverEvent {
+    /// Whether this event reports the cursor leaving the layer
+    pub fn is_leave(&self) -> bool {
+        self.phase == LayerHoverPhase::Leave
+    }
+}
+
+/// Deserialize a feature list, dropping entries that are not valid features
+/// so one bad feature does not discard the whole event
+fn skip_invalid_features<'de, D>(deserializer: D) -> Result<Vec<QueryFeature>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let values = Option::<Vec<serde_json::Value>>::deserialize(deserializer)?;
+    Ok(values
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|value| serde_json::from_value(value).ok())
+        .collect())
 }
 
 /// Event fired when map initialization succeeds
@@ -150,6 +374,63 @@ pub struct MapErrorEvent {
     pub message: Option<String>,
 }
 
+/// Event fired when the style or tile loading status changes
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MapLoadStateEvent {
+    /// Whether the style has finished loading
+    pub style_loaded: bool,
+    /// Whether all visible tiles have loaded
+    pub tiles_loaded: bool,
+}
+
+/// What a data event refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MapDataType {
+    /// A source's data or tiles
+    Source,
+    /// The style
+    Style,
+    /// A data type not known to this crate
+    #[serde(other)]
+    Other,
+}
+
+/// Event fired when map data loads or changes
+/// (`data`, `sourcedata`, `styledata`, `dataloading`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapDataEvent {
+    /// Whether the event concerns a source or the style
+    pub data_type: MapDataType,
+    /// Source ID for source events
+    #[serde(default)]
+    pub source_id: Option<String>,
+    /// MapLibre `sourceDataType` (`metadata`, `content`, `visibility`, `idle`)
+    #[serde(default)]
+    pub source_data_type: Option<String>,
+    /// Whether the source has finished loading, for source events
+    #[serde(default)]
+    pub is_source_loaded: Option<bool>,
+}
+
+/// Event fired when the map canvas is resized
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MapResizeEvent {
+    /// New canvas container width in CSS pixels
+    pub width: f64,
+    /// New canvas container height in CSS pixels
+    pub height: f64,
+}
+
+/// Event fired when the style references an image that has not been added
+///
+/// Add it with `MapHandle::load_image` under the same ID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapStyleImageMissingEvent {
+    /// ID of the missing image
+    pub id: String,
+}
+
 /// Internal event enum for communication from JS
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -165,6 +446,22 @@ pub enum MapEvent {
     DblClick(MapDblClickEvent),
     #[serde(rename = "contextmenu")]
     ContextMenu(MapContextMenuEvent),
+    #[serde(rename = "mousemove")]
+    MouseMove(MapMouseEvent),
+    #[serde(rename = "mousedown")]
+    MouseDown(MapMouseEvent),
+    #[serde(rename = "mouseup")]
+    MouseUp(MapMouseEvent),
+    #[serde(rename = "mouseout")]
+    MouseOut(MapMouseEvent),
+    #[serde(rename = "touchstart")]
+    TouchStart(MapTouchEvent),
+    #[serde(rename = "touchmove")]
+    TouchMove(MapTouchEvent),
+    #[serde(rename = "touchend")]
+    TouchEnd(MapTouchEvent),
+    #[serde(rename = "wheel")]
+    Wheel(MapWheelEvent),
     #[serde(rename = "marker_click")]
     MarkerClick(MarkerClickEvent),
     #[serde(rename = "marker_hover")]
@@ -175,6 +472,42 @@ pub enum MapEvent {
     MarkerDragEnd(MarkerDragEndEvent),
     #[serde(rename = "move")]
     Move(MapMoveEvent),
+    #[serde(rename = "load_state")]
+    LoadState(MapLoadStateEvent),
+    #[serde(rename = "idle")]
+    Idle,
+    #[serde(rename = "data")]
+    Data(MapDataEvent),
+    #[serde(rename = "sourcedata")]
+    SourceData(MapDataEvent),
+    #[serde(rename = "styledata")]
+    StyleData(MapDataEvent),
+    #[serde(rename = "dataloading")]
+    DataLoading(MapDataEvent),
+    #[serde(rename = "resize")]
+    Resize(MapResizeEvent),
+    #[serde(rename = "webglcontextlost")]
+    WebGlContextLost,
+    #[serde(rename = "styleimagemissing")]
+    StyleImageMissing(MapStyleImageMissingEvent),
+    #[serde(rename = "movestart")]
+    MoveStart(MapGestureEvent),
+    #[serde(rename = "zoomstart")]
+    ZoomStart(MapGestureEvent),
+    #[serde(rename = "rotatestart")]
+    RotateStart(MapGestureEvent),
+    #[serde(rename = "pitchstart")]
+    PitchStart(MapGestureEvent),
+    #[serde(rename = "dragstart")]
+    DragStart(MapGestureEvent),
+    #[serde(rename = "drag")]
+    Drag(MapGestureEvent),
+    #[serde(rename = "dragend")]
+    DragEnd(MapGestureEvent),
+    #[serde(rename = "boxzoomstart")]
+    BoxZoomStart(MapGestureEvent),
+    #[serde(rename = "boxzoomend")]
+    BoxZoomEnd(MapGestureEvent),
     #[serde(rename = "zoom")]
     Zoom(MapZoomEvent),
     #[serde(rename = "rotate")]
@@ -183,6 +516,10 @@ pub enum MapEvent {
     Pitch(MapPitchEvent),
     #[serde(rename = "layer_click")]
     LayerClick(LayerClickEvent),
+    #[serde(rename = "layer_dblclick")]
+    LayerDblClick(LayerClickEvent),
+    #[serde(rename = "layer_contextmenu")]
+    LayerContextMenu(LayerClickEvent),
     #[serde(rename = "layer_hover")]
     LayerHover(LayerHoverEvent),
 }
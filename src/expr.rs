@@ -0,0 +1,348 @@
+//! Typed builder for MapLibre style expressions.
+
+use std::ops::{Add, Div, Mul, Neg, Not, Rem, Sub};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// A MapLibre style expression
+///
+/// Serializes to the expression array format MapLibre expects, so it can be
+/// used anywhere a filter or paint/layout value is accepted. Plain numbers,
+/// strings and booleans convert into literal expressions.
+///
+/// # Examples
+///
+/// ```
+/// use dioxus_maplibre::{Expr, Interpolation};
+/// use serde_json::json;
+///
+/// let filter = Expr::get("population").greater_than(1000);
+/// assert_eq!(serde_json::to_value(&filter).unwrap(), json!([">", ["get", "population"], 1000]));
+///
+/// let radius = Expr::interpolate(Interpolation::Linear, Expr::zoom(), [(5.0, 2.0), (15.0, 12.0)]);
+/// assert_eq!(
+///     serde_json::to_value(&radius).unwrap(),
+///     json!(["interpolate", ["linear"], ["zoom"], 5.0, 2.0, 15.0, 12.0])
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+#[must_use]
+pub struct Expr(Value);
+
+/// Interpolation curve for `Expr::interpolate`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interpolation {
+    /// Linear interpolation between stops
+    Linear,
+    /// Exponential interpolation with the given base
+    Exponential(f64),
+    /// Cubic bezier interpolation with control points `(x1, y1, x2, y2)`
+    CubicBezier(f64, f64, f64, f64),
+}
+
+impl Interpolation {
+    fn to_value(self) -> Value {
+        match self {
+            Self::Linear => json!(["linear"]),
+            Self::Exponential(base) => json!(["exponential", base]),
+            Self::CubicBezier(x1, y1, x2, y2) => json!(["cubic-bezier", x1, y1, x2, y2]),
+        }
+    }
+}
+
+impl Expr {
+    /// Wrap a raw JSON expression (escape hatch for operators without a builder)
+    pub fn raw(value: Value) -> Self {
+        Self(value)
+    }
+
+    /// A literal value; arrays and objects are wrapped in `["literal", ...]`
+    pub fn literal(value: impl Serialize) -> Self {
+        match serde_json::to_value(value).unwrap_or_default() {
+            value @ (Value::Array(_) | Value::Object(_)) => Self(json!(["literal", value])),
+            value => Self(value),
+        }
+    }
+
+    /// Build an operator expression `[op, args...]`
+    fn op(name: &str, args: impl IntoIterator<Item = Self>) -> Self {
+        let mut items = vec![Value::String(name.to_string())];
+        items.extend(args.into_iter().map(|arg| arg.0));
+        Self(Value::Array(items))
+    }
+
+    // ── Lookup ───────────────────────────────────────────────────────────
+
+    /// `["get", name]` — read a feature property
+    pub fn get(name: impl Into<String>) -> Self {
+        Self(json!(["get", name.into()]))
+    }
+
+    /// `["has", name]` — test whether a feature property exists
+    pub fn has(name: impl Into<String>) -> Self {
+        Self(json!(["has", name.into()]))
+    }
+
+    /// `["feature-state", name]` — read a feature-state value
+    pub fn feature_state(name: impl Into<String>) -> Self {
+        Self(json!(["feature-state", name.into()]))
+    }
+
+    /// `["zoom"]` — the current zoom level
+    pub fn zoom() -> Self {
+        Self(json!(["zoom"]))
+    }
+
+    /// `["id"]` — the feature ID
+    pub fn id() -> Self {
+        Self(json!(["id"]))
+    }
+
+    /// `["geometry-type"]` — the feature's geometry type
+    pub fn geometry_type() -> Self {
+        Self(json!(["geometry-type"]))
+    }
+
+    /// `["properties"]` — the feature's properties object
+    pub fn properties() -> Self {
+        Self(json!(["properties"]))
+    }
+
+    /// `["in", self, ["literal", values]]` — test membership in a list of values
+    pub fn is_in<T: Serialize>(self, values: impl IntoIterator<Item = T>) -> Self {
+        let values: Vec<T> = values.into_iter().collect();
+        Self::op("in", [self, Self::literal(values)])
+    }
+
+    // ── Decisions ────────────────────────────────────────────────────────
+
+    /// `["match", input, label, output, ..., fallback]`
+    ///
+    /// Labels are emitted as bare literals: a string, a number, or an array
+    /// of either to match any of several values.
+    pub fn match_<L: Serialize, O: Into<Self>>(
+        input: impl Into<Self>,
+        cases: impl IntoIterator<Item = (L, O)>,
+        fallback: impl Into<Self>,
+    ) -> Self {
+        let mut args = vec![input.into()];
+        for (label, output) in cases {
+            let label = match serde_json::to_value(label).unwrap_or_default() {
+                Value::Array(mut items) if items.len() == 2 && items[0] == "literal" => {
+                    items.remove(1)
+                }
+                label => label,
+            };
+            args.push(Self(label));
+            args.push(output.into());
+        }
+        args.push(fallback.into());
+        Self::op("match", args)
+    }
+
+    /// `["case", condition, output, ..., fallback]`
+    pub fn case<O: Into<Self>>(
+        branches: impl IntoIterator<Item = (Self, O)>,
+        fallback: impl Into<Self>,
+    ) -> Self {
+        let mut args = Vec::new();
+        for (condition, output) in branches {
+            args.push(condition);
+            args.push(output.into());
+        }
+        args.push(fallback.into());
+        Self::op("case", args)
+    }
+
+    /// `["coalesce", ...]` — the first non-null value
+    pub fn coalesce(values: impl IntoIterator<Item = Self>) -> Self {
+        Self::op("coalesce", values)
+    }
+
+    /// `["all", ...]` — true if every condition is true
+    pub fn all(conditions: impl IntoIterator<Item = Self>) -> Self {
+        Self::op("all", conditions)
+    }
+
+    /// `["any", ...]` — true if any condition is true
+    pub fn any(conditions: impl IntoIterator<Item = Self>) -> Self {
+        Self::op("any", conditions)
+    }
+
+    // ── Ramps ────────────────────────────────────────────────────────────
+
+    /// `["interpolate", curve, input, stop, output, ...]`
+    pub fn interpolate<O: Into<Self>>(
+        interpolation: Interpolation,
+        input: impl Into<Self>,
+        stops: impl IntoIterator<Item = (f64, O)>,
+    ) -> Self {
+        let mut args = vec![Self(interpolation.to_value()), input.into()];
+        for (stop, output) in stops {
+            args.push(Self::from(stop));
+            args.push(output.into());
+        }
+        Self::op("interpolate", args)
+    }
+
+    /// `["step", input, default, stop, output, ...]`
+    pub fn step<O: Into<Self>>(
+        input: impl Into<Self>,
+        default: impl Into<Self>,
+        stops: impl IntoIterator<Item = (f64, O)>,
+    ) -> Self {
+        let mut args = vec![input.into(), default.into()];
+        for (stop, output) in stops {
+            args.push(Self::from(stop));
+            args.push(output.into());
+        }
+        Self::op("step", args)
+    }
+
+    // ── Comparison ───────────────────────────────────────────────────────
+
+    /// `["==", self, other]`
+    pub fn equals(self, other: impl Into<Self>) -> Self {
+        Self::op("==", [self, other.into()])
+    }
+
+    /// `["!=", self, other]`
+    pub fn not_equals(self, other: impl Into<Self>) -> Self {
+        Self::op("!=", [self, other.into()])
+    }
+
+    /// `["<", self, other]`
+    pub fn less_than(self, other: impl Into<Self>) -> Self {
+        Self::op("<", [self, other.into()])
+    }
+
+    /// `["<=", self, other]`
+    pub fn less_or_equal(self, other: impl Into<Self>) -> Self {
+        Self::op("<=", [self, other.into()])
+    }
+
+    /// `[">", self, other]`
+    pub fn greater_than(self, other: impl Into<Self>) -> Self {
+        Self::op(">", [self, other.into()])
+    }
+
+    /// `[">=", self, other]`
+    pub fn greater_or_equal(self, other: impl Into<Self>) -> Self {
+        Self::op(">=", [self, other.into()])
+    }
+
+    // ── Math ─────────────────────────────────────────────────────────────
+
+    /// `["^", self, exponent]`
+    pub fn pow(self, exponent: impl Into<Self>) -> Self {
+        Self::op("^", [self, exponent.into()])
+    }
+
+    /// `["abs", self]`
+    pub fn abs(self) -> Self {
+        Self::op("abs", [self])
+    }
+
+    /// `["sqrt", self]`
+    pub fn sqrt(self) -> Self {
+        Self::op("sqrt", [self])
+    }
+
+    /// `["round", self]`
+    pub fn round(self) -> Self {
+        Self::op("round", [self])
+    }
+
+    /// `["floor", self]`
+    pub fn floor(self) -> Self {
+        Self::op("floor", [self])
+    }
+
+    /// `["ceil", self]`
+    pub fn ceil(self) -> Self {
+        Self::op("ceil", [self])
+    }
+
+    /// `["min", ...]`
+    pub fn min(values: impl IntoIterator<Item = Self>) -> Self {
+        Self::op("min", values)
+    }
+
+    /// `["max", ...]`
+    pub fn max(values: impl IntoIterator<Item = Self>) -> Self {
+        Self::op("max", values)
+    }
+
+    // ── Types ────────────────────────────────────────────────────────────
+
+    /// `["to-number", self]`
+    pub fn to_number(self) -> Self {
+        Self::op("to-number", [self])
+    }
+
+    /// `["to-boolean", self]`
+    pub fn to_boolean(self) -> Self {
+        Self::op("to-boolean", [self])
+    }
+
+    /// Get the underlying JSON expression
+    pub fn as_value(&self) -> &Value {
+        &self.0
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $op:literal) => {
+        impl<R: Into<Expr>> $trait<R> for Expr {
+            type Output = Self;
+
+            fn $method(self, rhs: R) -> Self {
+                Self::op($op, [self, rhs.into()])
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, "+");
+impl_binary_op!(Sub, sub, "-");
+impl_binary_op!(Mul, mul, "*");
+impl_binary_op!(Div, div, "/");
+impl_binary_op!(Rem, rem, "%");
+
+impl Neg for Expr {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::op("-", [self])
+    }
+}
+
+impl Not for Expr {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::op("!", [self])
+    }
+}
+
+macro_rules! impl_from_literal {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Expr {
+                fn from(value: $ty) -> Self {
+                    Self(json!(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_literal!(f64, f32, i64, i32, u32, bool, &str, String);
+
+impl From<Expr> for Value {
+    fn from(value: Expr) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,58 @@
+//! Command batching MapHandle methods.
+
+use std::sync::{Arc, Mutex};
+
+use super::{MapCommand, MapHandle, lock};
+
+impl MapHandle {
+    /// Run several commands in a single bridge round trip.
+    ///
+    /// Fire-and-forget calls made on the handle passed to `f` are recorded and
+    /// then shipped as one eval, applied in order without other commands (such
+    /// as a style switch) interleaving. A failing command is logged and the
+    /// rest still run. Async methods (getters, `try_*`) are not batched and run
+    /// immediately. Nested batches join the outer one.
+    ///
+    /// ```rust,ignore
+    /// map.batch(|b| {
+    ///     b.add_geojson_source("points", options);
+    ///     b.add_layer(LayerOptions::circle("points-layer", "points"));
+    /// });
+    /// ```
+    pub fn batch(&self, f: impl FnOnce(&Self)) {
+        if self.batch.is_some() {
+            f(self);
+            return;
+        }
+
+        let scripts = Arc::new(Mutex::new(Vec::new()));
+        let batched = Self {
+            map_id: self.map_id.clone(),
+            batch: Some(Arc::clone(&scripts)),
+            #[cfg(feature = "testing")]
+            recorder: self
+                .recorder
+                .as_ref()
+                .map(|recorder| Arc::new(recorder.for_batch())),
+        };
+        f(&batched);
+
+        let scripts = std::mem::take(&mut *lock(&scripts));
+        #[cfg(feature = "testing")]
+        let commands = batched
+            .recorder
+            .as_ref()
+            .map(|recorder| std::mem::take(&mut *lock(&recorder.commands)))
+            .unwrap_or_default();
+        #[cfg(not(feature = "testing"))]
+        let commands = Vec::new();
+
+        if scripts.is_empty() && commands.is_empty() {
+            return;
+        }
+        self.fire_and_forget(
+            || MapCommand::Batch(commands),
+            || crate::interop::batch_js(&scripts),
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! Typed description of mutating MapHandle operations.
+
+use serde_json::Value;
+
+use crate::components::MapControlKind;
+use crate::options::{
+    ControlPosition, EaseToOptions, FeatureIdentifier, FitBoundsOptions, FlyToOptions, FogOptions,
+    GeoJsonSourceOptions, ImageSourceOptions, JumpToOptions, LayerHoverOptions, LayerOptions,
+    MapStyle, MarkerOptions, Padding, PopupOptions, RasterDemSourceOptions, RasterSourceOptions,
+    SetStyleOptions, SkyOptions, StyleImageOptions, StyleImageSource, TerrainOptions,
+    VectorSourceOptions,
+};
+use crate::types::{Bounds, LatLng};
+
+/// A mutating `MapHandle` call, as captured by the `testing` backend
+///
+/// Each variant mirrors one `MapHandle` method and carries its arguments.
+/// `try_*` methods record the same variant as their fire-and-forget form.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(feature = "testing"), allow(dead_code))]
+pub enum MapCommand {
+    // ── Sources ──────────────────────────────────────────────────────────
+    AddGeoJsonSource {
+        id: String,
+        options: GeoJsonSourceOptions,
+    },
+    AddVectorSource {
+        id: String,
+        options: VectorSourceOptions,
+    },
+    AddRasterSource {
+        id: String,
+        options: RasterSourceOptions,
+    },
+    AddRasterDemSource {
+        id: String,
+        options: RasterDemSourceOptions,
+    },
+    AddImageSource {
+        id: String,
+        options: ImageSourceOptions,
+    },
+    UpdateGeoJsonSource {
+        id: String,
+        data: Value,
+    },
+    RemoveSource {
+        id: String,
+    },
+
+    // ── Layers ───────────────────────────────────────────────────────────
+    AddLayer(LayerOptions),
+    RemoveLayer {
+        id: String,
+    },
+    SetPaintProperty {
+        layer_id: String,
+        name: String,
+        value: Value,
+    },
+    SetLayoutProperty {
+        layer_id: String,
+        name: String,
+        value: Value,
+    },
+    MoveLayer {
+        layer_id: String,
+        before_id: Option<String>,
+    },
+    SetFilter {
+        layer_id: String,
+        filter: Value,
+    },
+    OnLayerClick {
+        layer_id: String,
+    },
+    OffLayerClick {
+        layer_id: String,
+    },
+    OnLayerDblClick {
+        layer_id: String,
+    },
+    OffLayerDblClick {
+        layer_id: String,
+    },
+    OnLayerContextMenu {
+        layer_id: String,
+    },
+    OffLayerContextMenu {
+        layer_id: String,
+    },
+    OnLayerHover {
+        layer_id: String,
+        options: LayerHoverOptions,
+    },
+    OffLayerHover {
+        layer_id: String,
+    },
+
+    // ── Markers & popups ─────────────────────────────────────────────────
+    AddMarker {
+        id: String,
+        position: LatLng,
+        options: MarkerOptions,
+        element_id: Option<String>,
+    },
+    RemoveMarker {
+        id: String,
+    },
+    UpdateMarkerPosition {
+        id: String,
+        position: LatLng,
+    },
+    AddPopup {
+        id: String,
+        position: LatLng,
+        html: String,
+        options: PopupOptions,
+        element_id: Option<String>,
+    },
+    RemovePopup {
+        id: String,
+    },
+
+    // ── Controls ─────────────────────────────────────────────────────────
+    AddControl {
+        kind: MapControlKind,
+        position: ControlPosition,
+    },
+    RemoveControl {
+        kind: MapControlKind,
+        position: ControlPosition,
+    },
+
+    // ── Camera ───────────────────────────────────────────────────────────
+    FlyTo(FlyToOptions),
+    EaseTo(EaseToOptions),
+    JumpTo(JumpToOptions),
+    FitBounds {
+        bounds: Bounds,
+        options: FitBoundsOptions,
+    },
+    PanTo(LatLng),
+    PanBy {
+        x: i32,
+        y: i32,
+    },
+    ZoomTo(f64),
+    ZoomIn,
+    ZoomOut,
+    RotateTo(f64),
+    SetPitch(f64),
+    ResetNorth,
+    SetMoveEventThrottle(u32),
+    SetPadding(Padding),
+
+    // ── Feature state ────────────────────────────────────────────────────
+    SetFeatureState {
+        feature: FeatureIdentifier,
+        state: Value,
+    },
+    RemoveFeatureState(FeatureIdentifier),
+    RemoveFeatureStateKey {
+        feature: FeatureIdentifier,
+        key: String,
+    },
+    ClearFeatureStates {
+        source: String,
+        source_layer: Option<String>,
+    },
+    SetFeatureStates(Vec<(FeatureIdentifier, Value)>),
+
+    // ── Style, images & atmosphere ───────────────────────────────────────
+    SetStyle {
+        style: MapStyle,
+        options: SetStyleOptions,
+    },
+    LoadImage {
+        id: String,
+        url: String,
+    },
+    AddImage {
+        id: String,
+        source: StyleImageSource,
+        options: StyleImageOptions,
+    },
+    RemoveImage {
+        id: String,
+    },
+    SetTerrain(TerrainOptions),
+    RemoveTerrain,
+    SetSky(SkyOptions),
+    RemoveSky,
+    SetFog(FogOptions),
+    RemoveFog,
+
+    // ── Escape hatch & batching ──────────────────────────────────────────
+    /// Raw JS passed to `MapHandle::eval`
+    Eval(String),
+    /// Commands issued inside `MapHandle::batch`, in order
+    Batch(Vec<Self>),
+}
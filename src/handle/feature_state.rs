@@ -1,33 +1,137 @@
 //! Feature-state MapHandle methods.
 #![allow(clippy::needless_pass_by_value)]
 
-use super::MapHandle;
+use super::{MapCommand, MapHandle};
+use crate::error::MapResult;
 use crate::options::FeatureIdentifier;
 
 impl MapHandle {
     /// Set feature state for styling (hover effects, selection, etc.)
     pub fn set_feature_state(&self, feature: &FeatureIdentifier, state: serde_json::Value) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&state).unwrap_or_default();
-            crate::interop::set_feature_state_js(
-                &self.map_id,
-                &feature.source,
-                feature.id,
-                feature.source_layer.as_deref(),
-                &json,
-            )
-        });
+        self.fire_and_forget(
+            || MapCommand::SetFeatureState {
+                feature: feature.clone(),
+                state: state.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&state).unwrap_or_default();
+                crate::interop::set_feature_state_js(
+                    &self.map_id,
+                    &feature.source,
+                    &feature.id,
+                    feature.source_layer.as_deref(),
+                    &json,
+                )
+            },
+        );
     }
 
     /// Remove all feature state
     pub fn remove_feature_state(&self, feature: &FeatureIdentifier) {
-        self.fire_and_forget(|| {
-            crate::interop::remove_feature_state_js(
+        self.fire_and_forget(
+            || MapCommand::RemoveFeatureState(feature.clone()),
+            || {
+                crate::interop::remove_feature_state_js(
+                    &self.map_id,
+                    &feature.source,
+                    &feature.id,
+                    feature.source_layer.as_deref(),
+                )
+            },
+        );
+    }
+
+    /// Remove one key from a feature's state
+    pub fn remove_feature_state_key(&self, feature: &FeatureIdentifier, key: &str) {
+        self.fire_and_forget(
+            || MapCommand::RemoveFeatureStateKey {
+                feature: feature.clone(),
+                key: key.to_string(),
+            },
+            || {
+                crate::interop::remove_feature_state_key_js(
+                    &self.map_id,
+                    &feature.source,
+                    &feature.id,
+                    feature.source_layer.as_deref(),
+                    key,
+                )
+            },
+        );
+    }
+
+    /// Remove the state of every feature in a source (or one of its source layers)
+    pub fn clear_feature_states(&self, source: &str, source_layer: Option<&str>) {
+        self.fire_and_forget(
+            || MapCommand::ClearFeatureStates {
+                source: source.to_string(),
+                source_layer: source_layer.map(str::to_string),
+            },
+            || crate::interop::clear_feature_states_js(&self.map_id, source, source_layer),
+        );
+    }
+
+    /// Set the state of many features in a single eval
+    ///
+    /// Prefer this over calling `set_feature_state` in a loop when updating
+    /// large selections.
+    pub fn set_feature_states(&self, states: Vec<(FeatureIdentifier, serde_json::Value)>) {
+        if states.is_empty() {
+            return;
+        }
+        self.fire_and_forget(
+            || MapCommand::SetFeatureStates(states.clone()),
+            || {
+                let json = serde_json::to_string(&states).unwrap_or_default();
+                crate::interop::set_feature_states_js(&self.map_id, &json)
+            },
+        );
+    }
+
+    /// Get a feature's current state
+    pub async fn get_feature_state(
+        &self,
+        feature: &FeatureIdentifier,
+    ) -> Option<serde_json::Value> {
+        self.eval_query::<Option<serde_json::Value>>("get_feature_state", || {
+            crate::interop::get_feature_state_js(
                 &self.map_id,
                 &feature.source,
-                feature.id,
+                &feature.id,
                 feature.source_layer.as_deref(),
             )
-        });
+        })
+        .await
+        .flatten()
+    }
+
+    /// Set feature state, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::SourceMissing` if the feature's source does not exist.
+    pub async fn try_set_feature_state(
+        &self,
+        feature: &FeatureIdentifier,
+        state: serde_json::Value,
+    ) -> MapResult<()> {
+        self.eval_fallible(
+            "try_set_feature_state",
+            || MapCommand::SetFeatureState {
+                feature: feature.clone(),
+                state: state.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&state).unwrap_or_default();
+                crate::interop::try_set_feature_state_js(
+                    &self.map_id,
+                    &feature.source,
+                    &feature.id,
+                    feature.source_layer.as_deref(),
+                    &json,
+                )
+            },
+        )
+        .await
     }
 }
@@ -1,44 +1,126 @@
 //! Image sprite MapHandle methods.
-#![allow(clippy::unused_async)]
+#![allow(clippy::needless_pass_by_value)]
 
-use super::MapHandle;
-#[cfg(target_arch = "wasm32")]
-use dioxus::prelude::document;
+use super::{MapCommand, MapHandle};
+use crate::error::MapResult;
+use crate::options::{StyleImageOptions, StyleImageSource};
 
 impl MapHandle {
     /// Load an image from a URL and add it to the map's sprite
     pub fn load_image(&self, id: &str, url: &str) {
-        self.fire_and_forget(|| crate::interop::load_image_js(&self.map_id, id, url));
+        self.fire_and_forget(
+            || MapCommand::LoadImage {
+                id: id.to_string(),
+                url: url.to_string(),
+            },
+            || crate::interop::load_image_js(&self.map_id, id, url),
+        );
     }
 
-    /// Load an image and wait for it to complete (returns true on success)
-    #[cfg(target_arch = "wasm32")]
-    pub async fn load_image_async(&self, id: &str, url: &str) -> bool {
-        let js = crate::interop::load_image_async_js(&self.map_id, id, url);
-        document::eval(&js).join::<bool>().await.unwrap_or(false)
+    /// Add an image to the map's sprite from a URL, RGBA pixels, SVG markup or
+    /// an asset, replacing any image with the same ID
+    ///
+    /// The image is re-added after `set_style`. RGBA data whose length is not
+    /// `width * height * 4` is logged and not sent.
+    pub fn add_image(&self, id: &str, source: StyleImageSource, options: StyleImageOptions) {
+        if let Err(err) = source.validate() {
+            tracing::error!(map_id = %self.map_id, image_id = id, error = %err, "Invalid style image");
+            return;
+        }
+        self.fire_and_forget(
+            || MapCommand::AddImage {
+                id: id.to_string(),
+                source: source.clone(),
+                options: options.clone(),
+            },
+            || {
+                let spec = serde_json::json!({
+                    "source": source.to_js_spec(),
+                    "options": options,
+                });
+                crate::interop::add_image_js(&self.map_id, id, &spec.to_string())
+            },
+        );
+    }
+
+    /// Add an image to the map's sprite, replacing any image with the same ID,
+    /// reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::InvalidStyleSpec` if RGBA data has the wrong length
+    /// or MapLibre rejects the image, or `MapError::JsException` if the image
+    /// cannot be fetched or decoded.
+    pub async fn try_add_image(
+        &self,
+        id: &str,
+        source: StyleImageSource,
+        options: StyleImageOptions,
+    ) -> MapResult<()> {
+        source.validate()?;
+        self.eval_fallible(
+            "try_add_image",
+            || MapCommand::AddImage {
+                id: id.to_string(),
+                source: source.clone(),
+                options: options.clone(),
+            },
+            || {
+                let spec = serde_json::json!({
+                    "source": source.to_js_spec(),
+                    "options": options,
+                });
+                crate::interop::try_add_image_js(&self.map_id, id, &spec.to_string())
+            },
+        )
+        .await
     }
 
     /// Load an image and wait for it to complete (returns true on success)
-    #[cfg(not(target_arch = "wasm32"))]
-    pub async fn load_image_async(&self, _id: &str, _url: &str) -> bool {
-        false
+    pub async fn load_image_async(&self, id: &str, url: &str) -> bool {
+        self.record(|| MapCommand::LoadImage {
+            id: id.to_string(),
+            url: url.to_string(),
+        });
+        self.eval_query("load_image_async", || {
+            crate::interop::load_image_async_js(&self.map_id, id, url)
+        })
+        .await
+        .unwrap_or(false)
     }
 
-    /// Check if an image exists in the map's sprite
-    #[cfg(target_arch = "wasm32")]
-    pub async fn has_image(&self, id: &str) -> bool {
-        let js = crate::interop::has_image_js(&self.map_id, id);
-        document::eval(&js).join::<bool>().await.unwrap_or(false)
+    /// Load an image and add it to the map's sprite, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::ImageExists` if the ID is taken, or
+    /// `MapError::JsException` if the image cannot be fetched or decoded.
+    pub async fn try_load_image(&self, id: &str, url: &str) -> MapResult<()> {
+        self.eval_fallible(
+            "try_load_image",
+            || MapCommand::LoadImage {
+                id: id.to_string(),
+                url: url.to_string(),
+            },
+            || crate::interop::try_load_image_js(&self.map_id, id, url),
+        )
+        .await
     }
 
     /// Check if an image exists in the map's sprite
-    #[cfg(not(target_arch = "wasm32"))]
-    pub async fn has_image(&self, _id: &str) -> bool {
-        false
+    pub async fn has_image(&self, id: &str) -> bool {
+        self.eval_query("has_image", || {
+            crate::interop::has_image_js(&self.map_id, id)
+        })
+        .await
+        .unwrap_or(false)
     }
 
     /// Remove an image from the map's sprite
     pub fn remove_image(&self, id: &str) {
-        self.fire_and_forget(|| crate::interop::remove_image_js(&self.map_id, id));
+        self.fire_and_forget(
+            || MapCommand::RemoveImage { id: id.to_string() },
+            || crate::interop::remove_image_js(&self.map_id, id),
+        );
     }
 }
@@ -1,25 +1,98 @@
 //! Layer event registration MapHandle methods.
 
-use super::MapHandle;
+use super::{MapCommand, MapHandle};
+use crate::options::LayerHoverOptions;
 
 impl MapHandle {
     /// Register a click handler on a layer (events dispatched via `on_layer_click`).
     pub fn on_layer_click(&self, layer_id: &str) {
-        self.fire_and_forget(|| crate::interop::register_layer_click_js(&self.map_id, layer_id));
+        self.fire_and_forget(
+            || MapCommand::OnLayerClick {
+                layer_id: layer_id.to_string(),
+            },
+            || crate::interop::register_layer_click_js(&self.map_id, layer_id),
+        );
     }
 
     /// Unregister a click handler on a layer.
     pub fn off_layer_click(&self, layer_id: &str) {
-        self.fire_and_forget(|| crate::interop::unregister_layer_click_js(&self.map_id, layer_id));
+        self.fire_and_forget(
+            || MapCommand::OffLayerClick {
+                layer_id: layer_id.to_string(),
+            },
+            || crate::interop::unregister_layer_click_js(&self.map_id, layer_id),
+        );
+    }
+
+    /// Register a double-click handler on a layer (events dispatched via `on_layer_dblclick`).
+    pub fn on_layer_dblclick(&self, layer_id: &str) {
+        self.fire_and_forget(
+            || MapCommand::OnLayerDblClick {
+                layer_id: layer_id.to_string(),
+            },
+            || crate::interop::register_layer_dblclick_js(&self.map_id, layer_id),
+        );
+    }
+
+    /// Unregister the double-click handler on a layer.
+    pub fn off_layer_dblclick(&self, layer_id: &str) {
+        self.fire_and_forget(
+            || MapCommand::OffLayerDblClick {
+                layer_id: layer_id.to_string(),
+            },
+            || crate::interop::unregister_layer_dblclick_js(&self.map_id, layer_id),
+        );
+    }
+
+    /// Register a context menu handler on a layer (events dispatched via `on_layer_contextmenu`).
+    pub fn on_layer_contextmenu(&self, layer_id: &str) {
+        self.fire_and_forget(
+            || MapCommand::OnLayerContextMenu {
+                layer_id: layer_id.to_string(),
+            },
+            || crate::interop::register_layer_contextmenu_js(&self.map_id, layer_id),
+        );
+    }
+
+    /// Unregister the context menu handler on a layer.
+    pub fn off_layer_contextmenu(&self, layer_id: &str) {
+        self.fire_and_forget(
+            || MapCommand::OffLayerContextMenu {
+                layer_id: layer_id.to_string(),
+            },
+            || crate::interop::unregister_layer_contextmenu_js(&self.map_id, layer_id),
+        );
     }
 
     /// Register hover handlers on a layer (events dispatched via `on_layer_hover`).
     pub fn on_layer_hover(&self, layer_id: &str) {
-        self.fire_and_forget(|| crate::interop::register_layer_hover_js(&self.map_id, layer_id));
+        self.on_layer_hover_with(layer_id, LayerHoverOptions::default());
+    }
+
+    /// Register hover handlers on a layer with hover tracking options.
+    pub fn on_layer_hover_with(&self, layer_id: &str, options: LayerHoverOptions) {
+        self.fire_and_forget(
+            || MapCommand::OnLayerHover {
+                layer_id: layer_id.to_string(),
+                options,
+            },
+            || {
+                crate::interop::register_layer_hover_js(
+                    &self.map_id,
+                    layer_id,
+                    options.feature_state,
+                )
+            },
+        );
     }
 
     /// Unregister hover handlers on a layer.
     pub fn off_layer_hover(&self, layer_id: &str) {
-        self.fire_and_forget(|| crate::interop::unregister_layer_hover_js(&self.map_id, layer_id));
+        self.fire_and_forget(
+            || MapCommand::OffLayerHover {
+                layer_id: layer_id.to_string(),
+            },
+            || crate::interop::unregister_layer_hover_js(&self.map_id, layer_id),
+        );
     }
 }
@@ -1,37 +1,70 @@
 //! Layer-related MapHandle methods.
 #![allow(clippy::needless_pass_by_value)]
 
-use super::MapHandle;
+use super::{MapCommand, MapHandle};
+use crate::error::MapResult;
 use crate::options::LayerOptions;
 
 impl MapHandle {
     /// Add a layer to the map
     pub fn add_layer(&self, options: LayerOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options).unwrap_or_default();
-            crate::interop::add_layer_js(&self.map_id, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::AddLayer(options.clone()),
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::add_layer_js(&self.map_id, &json)
+            },
+        );
     }
 
     /// Remove a layer from the map
     pub fn remove_layer(&self, id: &str) {
-        self.fire_and_forget(|| crate::interop::remove_layer_js(&self.map_id, id));
+        self.fire_and_forget(
+            || MapCommand::RemoveLayer { id: id.to_string() },
+            || crate::interop::remove_layer_js(&self.map_id, id),
+        );
     }
 
     /// Set a paint property on a layer
-    pub fn set_paint_property(&self, layer_id: &str, name: &str, value: serde_json::Value) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&value).unwrap_or_default();
-            crate::interop::set_paint_property_js(&self.map_id, layer_id, name, &json)
-        });
+    pub fn set_paint_property(
+        &self,
+        layer_id: &str,
+        name: &str,
+        value: impl Into<serde_json::Value>,
+    ) {
+        let value = value.into();
+        self.fire_and_forget(
+            || MapCommand::SetPaintProperty {
+                layer_id: layer_id.to_string(),
+                name: name.to_string(),
+                value: value.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&value).unwrap_or_default();
+                crate::interop::set_paint_property_js(&self.map_id, layer_id, name, &json)
+            },
+        );
     }
 
     /// Set a layout property on a layer
-    pub fn set_layout_property(&self, layer_id: &str, name: &str, value: serde_json::Value) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&value).unwrap_or_default();
-            crate::interop::set_layout_property_js(&self.map_id, layer_id, name, &json)
-        });
+    pub fn set_layout_property(
+        &self,
+        layer_id: &str,
+        name: &str,
+        value: impl Into<serde_json::Value>,
+    ) {
+        let value = value.into();
+        self.fire_and_forget(
+            || MapCommand::SetLayoutProperty {
+                layer_id: layer_id.to_string(),
+                name: name.to_string(),
+                value: value.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&value).unwrap_or_default();
+                crate::interop::set_layout_property_js(&self.map_id, layer_id, name, &json)
+            },
+        );
     }
 
     /// Move a layer to a different position in the layer stack
@@ -39,14 +72,157 @@ impl MapHandle {
     /// If `before_id` is `Some`, the layer is moved before that layer.
     /// If `before_id` is `None`, the layer is moved to the top.
     pub fn move_layer(&self, layer_id: &str, before_id: Option<&str>) {
-        self.fire_and_forget(|| crate::interop::move_layer_js(&self.map_id, layer_id, before_id));
+        self.fire_and_forget(
+            || MapCommand::MoveLayer {
+                layer_id: layer_id.to_string(),
+                before_id: before_id.map(ToString::to_string),
+            },
+            || crate::interop::move_layer_js(&self.map_id, layer_id, before_id),
+        );
     }
 
     /// Set a filter on a layer
-    pub fn set_filter(&self, layer_id: &str, filter: serde_json::Value) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&filter).unwrap_or_default();
-            crate::interop::set_filter_js(&self.map_id, layer_id, &json)
-        });
+    ///
+    /// Accepts raw JSON or a typed `Expr`.
+    pub fn set_filter(&self, layer_id: &str, filter: impl Into<serde_json::Value>) {
+        let filter = filter.into();
+        self.fire_and_forget(
+            || MapCommand::SetFilter {
+                layer_id: layer_id.to_string(),
+                filter: filter.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&filter).unwrap_or_default();
+                crate::interop::set_filter_js(&self.map_id, layer_id, &json)
+            },
+        );
+    }
+
+    /// Add a layer to the map, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::LayerExists`, `MapError::SourceMissing`, or
+    /// `MapError::InvalidStyleSpec` when MapLibre rejects the layer definition.
+    pub async fn try_add_layer(&self, options: LayerOptions) -> MapResult<()> {
+        self.eval_fallible(
+            "try_add_layer",
+            || MapCommand::AddLayer(options.clone()),
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::try_add_layer_js(&self.map_id, &json)
+            },
+        )
+        .await
+    }
+
+    /// Remove a layer from the map, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::LayerMissing` if no layer has this ID.
+    pub async fn try_remove_layer(&self, id: &str) -> MapResult<()> {
+        self.eval_fallible(
+            "try_remove_layer",
+            || MapCommand::RemoveLayer { id: id.to_string() },
+            || crate::interop::try_remove_layer_js(&self.map_id, id),
+        )
+        .await
+    }
+
+    /// Move a layer in the layer stack, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::LayerMissing` if the layer or `before_id` does not exist.
+    pub async fn try_move_layer(&self, layer_id: &str, before_id: Option<&str>) -> MapResult<()> {
+        self.eval_fallible(
+            "try_move_layer",
+            || MapCommand::MoveLayer {
+                layer_id: layer_id.to_string(),
+                before_id: before_id.map(ToString::to_string),
+            },
+            || crate::interop::try_move_layer_js(&self.map_id, layer_id, before_id),
+        )
+        .await
+    }
+
+    /// Set a paint property on a layer, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::LayerMissing`, or `MapError::InvalidStyleSpec` for an invalid value.
+    pub async fn try_set_paint_property(
+        &self,
+        layer_id: &str,
+        name: &str,
+        value: impl Into<serde_json::Value>,
+    ) -> MapResult<()> {
+        let value = value.into();
+        self.eval_fallible(
+            "try_set_paint_property",
+            || MapCommand::SetPaintProperty {
+                layer_id: layer_id.to_string(),
+                name: name.to_string(),
+                value: value.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&value).unwrap_or_default();
+                crate::interop::try_set_paint_property_js(&self.map_id, layer_id, name, &json)
+            },
+        )
+        .await
+    }
+
+    /// Set a layout property on a layer, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::LayerMissing`, or `MapError::InvalidStyleSpec` for an invalid value.
+    pub async fn try_set_layout_property(
+        &self,
+        layer_id: &str,
+        name: &str,
+        value: impl Into<serde_json::Value>,
+    ) -> MapResult<()> {
+        let value = value.into();
+        self.eval_fallible(
+            "try_set_layout_property",
+            || MapCommand::SetLayoutProperty {
+                layer_id: layer_id.to_string(),
+                name: name.to_string(),
+                value: value.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&value).unwrap_or_default();
+                crate::interop::try_set_layout_property_js(&self.map_id, layer_id, name, &json)
+            },
+        )
+        .await
+    }
+
+    /// Set a filter on a layer, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::LayerMissing`, or `MapError::InvalidStyleSpec` for an invalid filter.
+    pub async fn try_set_filter(
+        &self,
+        layer_id: &str,
+        filter: impl Into<serde_json::Value>,
+    ) -> MapResult<()> {
+        let filter = filter.into();
+        self.eval_fallible(
+            "try_set_filter",
+            || MapCommand::SetFilter {
+                layer_id: layer_id.to_string(),
+                filter: filter.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&filter).unwrap_or_default();
+                crate::interop::try_set_filter_js(&self.map_id, layer_id, &json)
+            },
+        )
+        .await
     }
 }
@@ -1,28 +1,138 @@
 //! Marker-related MapHandle methods.
 #![allow(clippy::needless_pass_by_value)]
 
-use super::MapHandle;
+use super::{MapCommand, MapHandle};
+use crate::error::MapResult;
 use crate::options::MarkerOptions;
 use crate::types::LatLng;
 
 impl MapHandle {
     /// Add a marker at the given position
     pub fn add_marker(&self, id: &str, position: LatLng, options: MarkerOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options).unwrap_or_default();
-            crate::interop::add_marker_js(&self.map_id, id, position.lat, position.lng, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::AddMarker {
+                id: id.to_string(),
+                position,
+                options: options.clone(),
+                element_id: None,
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::add_marker_js(
+                    &self.map_id,
+                    id,
+                    position.lat,
+                    position.lng,
+                    &json,
+                    None,
+                )
+            },
+        );
+    }
+
+    /// Add a marker that uses an existing DOM element as its content
+    ///
+    /// The element is moved into the marker and handed back to its original
+    /// parent when the marker is removed. `color` and `emoji` options are ignored.
+    pub fn add_marker_with_element(
+        &self,
+        id: &str,
+        position: LatLng,
+        options: MarkerOptions,
+        element_id: &str,
+    ) {
+        self.fire_and_forget(
+            || MapCommand::AddMarker {
+                id: id.to_string(),
+                position,
+                options: options.clone(),
+                element_id: Some(element_id.to_string()),
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::add_marker_js(
+                    &self.map_id,
+                    id,
+                    position.lat,
+                    position.lng,
+                    &json,
+                    Some(element_id),
+                )
+            },
+        );
     }
 
     /// Remove a marker
     pub fn remove_marker(&self, id: &str) {
-        self.fire_and_forget(|| crate::interop::remove_marker_js(&self.map_id, id));
+        self.fire_and_forget(
+            || MapCommand::RemoveMarker { id: id.to_string() },
+            || crate::interop::remove_marker_js(&self.map_id, id),
+        );
     }
 
     /// Update a marker's position
     pub fn update_marker_position(&self, id: &str, position: LatLng) {
-        self.fire_and_forget(|| {
-            crate::interop::update_marker_position_js(&self.map_id, id, position.lat, position.lng)
-        });
+        self.fire_and_forget(
+            || MapCommand::UpdateMarkerPosition {
+                id: id.to_string(),
+                position,
+            },
+            || {
+                crate::interop::update_marker_position_js(
+                    &self.map_id,
+                    id,
+                    position.lat,
+                    position.lng,
+                )
+            },
+        );
+    }
+
+    /// Add a marker at the given position, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::JsException` if MapLibre fails to create the marker.
+    pub async fn try_add_marker(
+        &self,
+        id: &str,
+        position: LatLng,
+        options: MarkerOptions,
+    ) -> MapResult<()> {
+        self.eval_fallible(
+            "try_add_marker",
+            || MapCommand::AddMarker {
+                id: id.to_string(),
+                position,
+                options: options.clone(),
+                element_id: None,
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::try_add_marker_js(
+                    &self.map_id,
+                    id,
+                    position.lat,
+                    position.lng,
+                    &json,
+                    None,
+                )
+            },
+        )
+        .await
+    }
+
+    /// Remove a marker, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::MarkerMissing` if no marker has this ID.
+    pub async fn try_remove_marker(&self, id: &str) -> MapResult<()> {
+        self.eval_fallible(
+            "try_remove_marker",
+            || MapCommand::RemoveMarker { id: id.to_string() },
+            || crate::interop::try_remove_marker_js(&self.map_id, id),
+        )
+        .await
     }
 }
@@ -1,5 +1,9 @@
 //! MapHandle - the primary API for interacting with a MapLibre map.
+// wasm32 is single-threaded, so handle futures never need to be `Send`.
+#![allow(clippy::future_not_send)]
 
+mod batch;
+mod command;
 mod controls;
 mod escape_hatch;
 mod feature_state;
@@ -16,22 +20,106 @@ mod sources;
 mod style;
 mod terrain_atmosphere;
 
+#[cfg(feature = "testing")]
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use serde::de::DeserializeOwned;
+
+pub use command::MapCommand;
+
+use crate::error::{BridgeResult, MapError, MapResult};
 use crate::options::ControlPosition;
 
+/// Command log and scripted responses behind a `testing::MockMap` handle
+#[cfg(feature = "testing")]
+#[derive(Debug, Default)]
+pub(crate) struct Recorder {
+    pub(crate) commands: Mutex<Vec<MapCommand>>,
+    /// Queued results per method name, consumed oldest first
+    pub(crate) responses: Arc<Mutex<HashMap<String, VecDeque<MapResult<serde_json::Value>>>>>,
+}
+
+#[cfg(feature = "testing")]
+impl Recorder {
+    /// A fresh command log that still answers from the same scripted responses
+    fn for_batch(&self) -> Self {
+        Self {
+            commands: Mutex::default(),
+            responses: Arc::clone(&self.responses),
+        }
+    }
+
+    fn record(&self, command: MapCommand) {
+        lock(&self.commands).push(command);
+    }
+
+    fn next_response(&self, method: &str) -> Option<MapResult<serde_json::Value>> {
+        lock(&self.responses).get_mut(method)?.pop_front()
+    }
+}
+
 /// A handle to a MapLibre map instance.
 ///
 /// This is a lightweight `Clone` wrapper. Store it in a `Signal<Option<MapHandle>>`
 /// and set it in the `on_ready` callback.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// # Fallible operations
+///
+/// Mutating methods send their command and return immediately, logging any
+/// failure in the browser console. These have `try_*` counterparts that wait
+/// for the result and return a [`MapResult`]:
+///
+/// - sources: `add_geojson_source`, `add_vector_source`, `add_raster_source`,
+///   `add_raster_dem_source`, `add_image_source`, `update_geojson_source`,
+///   `remove_source`
+/// - layers: `add_layer`, `remove_layer`, `move_layer`, `set_paint_property`,
+///   `set_layout_property`, `set_filter`, `set_feature_state`
+/// - style: `set_style_with` (as `try_set_style`), `set_terrain`, `set_sky`,
+///   `set_fog`
+/// - markers and popups: `add_marker`, `remove_marker`, `add_popup`
+/// - images: `load_image`, `add_image`
+///
+/// Camera movements, controls, layer event registration and the remaining
+/// removals cannot fail in a way worth reporting and stay fire-and-forget.
+#[derive(Debug, Clone)]
 pub struct MapHandle {
     map_id: String,
+    /// Commands recorded while inside `MapHandle::batch`
+    batch: Option<Arc<Mutex<Vec<String>>>>,
+    /// Typed command log used instead of the JS bridge by `testing::MockMap`
+    #[cfg(feature = "testing")]
+    recorder: Option<Arc<Recorder>>,
 }
 
+impl PartialEq for MapHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.map_id == other.map_id
+    }
+}
+
+impl Eq for MapHandle {}
+
 impl MapHandle {
     /// Create a new `MapHandle` (called internally by the `Map` component).
     #[allow(dead_code)] // Used only on wasm32 target
     pub(crate) fn new(map_id: String) -> Self {
-        Self { map_id }
+        Self {
+            map_id,
+            batch: None,
+            #[cfg(feature = "testing")]
+            recorder: None,
+        }
+    }
+
+    /// Create a handle that records commands instead of evaluating JS.
+    #[cfg(feature = "testing")]
+    pub(crate) fn recording(map_id: String, recorder: Arc<Recorder>) -> Self {
+        Self {
+            map_id,
+            batch: None,
+            recorder: Some(recorder),
+        }
     }
 
     /// Get the internal map ID (useful for debugging).
@@ -40,32 +128,117 @@ impl MapHandle {
     }
 
     /// Fire-and-forget: spawn an async eval that we don't wait for.
-    #[cfg(target_arch = "wasm32")]
-    pub(crate) fn fire_and_forget(&self, js_fn: impl FnOnce() -> String) {
-        let js = js_fn();
-        dioxus::prelude::spawn(async move {
-            let _ = document::eval(&js).await;
-        });
+    ///
+    /// `command` describes the call for the `testing` recorder and is only
+    /// built when one is attached. Inside `batch` the JS is recorded instead
+    /// of evaluated.
+    #[cfg_attr(not(feature = "testing"), allow(unused_variables))]
+    pub(crate) fn fire_and_forget(
+        &self,
+        command: impl FnOnce() -> MapCommand,
+        js_fn: impl FnOnce() -> String,
+    ) {
+        #[cfg(feature = "testing")]
+        if let Some(recorder) = &self.recorder {
+            recorder.record(command());
+            return;
+        }
+
+        if let Some(batch) = &self.batch {
+            lock(batch).push(js_fn());
+        } else {
+            // No-op on non-wasm targets.
+            #[cfg(target_arch = "wasm32")]
+            {
+                let js = js_fn();
+                dioxus::prelude::spawn(async move {
+                    let _ = document::eval(&js).await;
+                });
+            }
+        }
     }
 
-    #[allow(clippy::unused_self)]
-    #[cfg(not(target_arch = "wasm32"))]
-    pub(crate) fn fire_and_forget(&self, _js_fn: impl FnOnce() -> String) {
-        // No-op on non-wasm targets.
+    /// Evaluate a fallible bridge snippet and decode its result envelope.
+    ///
+    /// With a `testing` recorder attached, `command` is recorded and the
+    /// result scripted for `method` is returned instead (`Ok` by default).
+    #[cfg_attr(not(feature = "testing"), allow(unused_variables))]
+    pub(crate) async fn eval_fallible<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        command: impl FnOnce() -> MapCommand,
+        js_fn: impl FnOnce() -> String,
+    ) -> MapResult<T> {
+        #[cfg(feature = "testing")]
+        if let Some(recorder) = &self.recorder {
+            recorder.record(command());
+            return recorder
+                .next_response(method)
+                .unwrap_or(Ok(serde_json::Value::Null))
+                .and_then(decode_scripted);
+        }
+
+        eval_js::<BridgeResult<T>>(js_fn())
+            .await
+            .and_then(BridgeResult::into_result)
     }
 
-    /// Execute raw JS without wrapping (for escape hatch).
-    #[cfg(target_arch = "wasm32")]
-    pub(crate) fn eval_raw(&self, js: &str) {
-        let js = js.to_string();
-        dioxus::prelude::spawn(async move {
-            let _ = document::eval(&js).await;
-        });
+    /// Evaluate a snippet that returns a value; `None` if it fails.
+    ///
+    /// With a `testing` recorder attached, returns the value scripted for
+    /// `method`, or `None` when nothing was scripted.
+    #[cfg_attr(not(feature = "testing"), allow(unused_variables))]
+    pub(crate) async fn eval_query<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        js_fn: impl FnOnce() -> String,
+    ) -> Option<T> {
+        #[cfg(feature = "testing")]
+        if let Some(recorder) = &self.recorder {
+            return recorder
+                .next_response(method)?
+                .and_then(decode_scripted)
+                .ok();
+        }
+
+        eval_js(js_fn()).await.ok()
     }
 
-    #[allow(clippy::unused_self)]
-    #[cfg(not(target_arch = "wasm32"))]
-    pub(crate) fn eval_raw(&self, _js: &str) {}
+    /// Record `command` when a `testing` recorder is attached.
+    #[cfg_attr(not(feature = "testing"), allow(unused_variables, clippy::unused_self))]
+    pub(crate) fn record(&self, command: impl FnOnce() -> MapCommand) {
+        #[cfg(feature = "testing")]
+        if let Some(recorder) = &self.recorder {
+            recorder.record(command());
+        }
+    }
+}
+
+/// Evaluate `js` and decode the value it returns.
+#[cfg(target_arch = "wasm32")]
+async fn eval_js<T: DeserializeOwned>(js: String) -> MapResult<T> {
+    document::eval(&js)
+        .join::<T>()
+        .await
+        .map_err(|err| MapError::Bridge {
+            message: err.to_string(),
+        })
+}
+
+#[allow(clippy::unused_async)]
+#[cfg(not(target_arch = "wasm32"))]
+async fn eval_js<T>(_js: String) -> MapResult<T> {
+    Err(MapError::Bridge {
+        message: "the MapLibre bridge is only available on wasm32 targets".to_string(),
+    })
+}
+
+/// Decode a response scripted through `testing::MockMap`.
+#[cfg(feature = "testing")]
+fn decode_scripted<T: DeserializeOwned>(value: serde_json::Value) -> MapResult<T> {
+    serde_json::from_value(value).map_err(|err| MapError::Bridge {
+        message: format!("scripted response does not match the return type: {err}"),
+    })
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -79,3 +252,8 @@ pub(crate) fn control_position_str(pos: ControlPosition) -> &'static str {
         ControlPosition::BottomRight => "bottom-right",
     }
 }
+
+/// Lock a command buffer, recovering the data if a panicking closure poisoned it.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
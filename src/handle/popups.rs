@@ -1,21 +1,113 @@
 //! Popup-related MapHandle methods.
 #![allow(clippy::needless_pass_by_value)]
 
-use super::MapHandle;
+use super::{MapCommand, MapHandle};
+use crate::error::MapResult;
 use crate::options::PopupOptions;
 use crate::types::LatLng;
 
 impl MapHandle {
     /// Add a standalone popup at a position
     pub fn add_popup(&self, id: &str, position: LatLng, html: &str, options: PopupOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options).unwrap_or_default();
-            crate::interop::add_popup_js(&self.map_id, id, position.lat, position.lng, html, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::AddPopup {
+                id: id.to_string(),
+                position,
+                html: html.to_string(),
+                options: options.clone(),
+                element_id: None,
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::add_popup_js(
+                    &self.map_id,
+                    id,
+                    position.lat,
+                    position.lng,
+                    html,
+                    &json,
+                    None,
+                )
+            },
+        );
+    }
+
+    /// Add a standalone popup at a position, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::JsException` if MapLibre fails to create the popup.
+    pub async fn try_add_popup(
+        &self,
+        id: &str,
+        position: LatLng,
+        html: &str,
+        options: PopupOptions,
+    ) -> MapResult<()> {
+        self.eval_fallible(
+            "try_add_popup",
+            || MapCommand::AddPopup {
+                id: id.to_string(),
+                position,
+                html: html.to_string(),
+                options: options.clone(),
+                element_id: None,
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::try_add_popup_js(
+                    &self.map_id,
+                    id,
+                    position.lat,
+                    position.lng,
+                    html,
+                    &json,
+                    None,
+                )
+            },
+        )
+        .await
+    }
+
+    /// Add a standalone popup that shows an existing DOM element as its content
+    ///
+    /// The element is moved into the popup and handed back to its original
+    /// parent when the popup closes or is removed.
+    pub fn add_popup_with_element(
+        &self,
+        id: &str,
+        position: LatLng,
+        element_id: &str,
+        options: PopupOptions,
+    ) {
+        self.fire_and_forget(
+            || MapCommand::AddPopup {
+                id: id.to_string(),
+                position,
+                html: String::new(),
+                options: options.clone(),
+                element_id: Some(element_id.to_string()),
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::add_popup_js(
+                    &self.map_id,
+                    id,
+                    position.lat,
+                    position.lng,
+                    "",
+                    &json,
+                    Some(element_id),
+                )
+            },
+        );
     }
 
     /// Remove a popup
     pub fn remove_popup(&self, id: &str) {
-        self.fire_and_forget(|| crate::interop::remove_popup_js(&self.map_id, id));
+        self.fire_and_forget(
+            || MapCommand::RemovePopup { id: id.to_string() },
+            || crate::interop::remove_popup_js(&self.map_id, id),
+        );
     }
 }
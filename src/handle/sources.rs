@@ -1,7 +1,8 @@
 //! Source-related MapHandle methods.
 #![allow(clippy::needless_pass_by_value)]
 
-use super::MapHandle;
+use super::{MapCommand, MapHandle};
+use crate::error::MapResult;
 use crate::options::{
     GeoJsonSourceOptions, ImageSourceOptions, RasterDemSourceOptions, RasterSourceOptions,
     VectorSourceOptions,
@@ -10,54 +11,266 @@ use crate::options::{
 impl MapHandle {
     /// Add a GeoJSON source to the map
     pub fn add_geojson_source(&self, id: &str, options: GeoJsonSourceOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options).unwrap_or_default();
-            crate::interop::add_geojson_source_js(&self.map_id, id, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::AddGeoJsonSource {
+                id: id.to_string(),
+                options: options.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::add_geojson_source_js(&self.map_id, id, &json)
+            },
+        );
     }
 
     /// Add a vector tile source to the map
     pub fn add_vector_source(&self, id: &str, options: VectorSourceOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options).unwrap_or_default();
-            crate::interop::add_vector_source_js(&self.map_id, id, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::AddVectorSource {
+                id: id.to_string(),
+                options: options.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::add_vector_source_js(&self.map_id, id, &json)
+            },
+        );
     }
 
     /// Add a raster tile source to the map
     pub fn add_raster_source(&self, id: &str, options: RasterSourceOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options).unwrap_or_default();
-            crate::interop::add_raster_source_js(&self.map_id, id, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::AddRasterSource {
+                id: id.to_string(),
+                options: options.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::add_raster_source_js(&self.map_id, id, &json)
+            },
+        );
     }
 
     /// Add a raster DEM source (for terrain)
     pub fn add_raster_dem_source(&self, id: &str, options: RasterDemSourceOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options).unwrap_or_default();
-            crate::interop::add_raster_dem_source_js(&self.map_id, id, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::AddRasterDemSource {
+                id: id.to_string(),
+                options: options.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::add_raster_dem_source_js(&self.map_id, id, &json)
+            },
+        );
     }
 
     /// Add an image source to the map
     pub fn add_image_source(&self, id: &str, options: ImageSourceOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options).unwrap_or_default();
-            crate::interop::add_image_source_js(&self.map_id, id, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::AddImageSource {
+                id: id.to_string(),
+                options: options.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::add_image_source_js(&self.map_id, id, &json)
+            },
+        );
     }
 
     /// Update the data of an existing GeoJSON source
-    pub fn update_geojson_source(&self, id: &str, data: serde_json::Value) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&data).unwrap_or_default();
-            crate::interop::update_geojson_source_js(&self.map_id, id, &json)
-        });
+    ///
+    /// Accepts raw JSON or a typed `Geometry`, `Feature` or `FeatureCollection`.
+    pub fn update_geojson_source(&self, id: &str, data: impl Into<serde_json::Value>) {
+        let data = data.into();
+        self.fire_and_forget(
+            || MapCommand::UpdateGeoJsonSource {
+                id: id.to_string(),
+                data: data.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&data).unwrap_or_default();
+                crate::interop::update_geojson_source_js(&self.map_id, id, &json)
+            },
+        );
     }
 
     /// Remove a source from the map
     pub fn remove_source(&self, id: &str) {
-        self.fire_and_forget(|| crate::interop::remove_source_js(&self.map_id, id));
+        self.fire_and_forget(
+            || MapCommand::RemoveSource { id: id.to_string() },
+            || crate::interop::remove_source_js(&self.map_id, id),
+        );
+    }
+
+    /// Add a GeoJSON source, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::SourceExists` if the ID is taken, or the error MapLibre reported.
+    pub async fn try_add_geojson_source(
+        &self,
+        id: &str,
+        options: GeoJsonSourceOptions,
+    ) -> MapResult<()> {
+        self.try_add_source(
+            "try_add_geojson_source",
+            || MapCommand::AddGeoJsonSource {
+                id: id.to_string(),
+                options: options.clone(),
+            },
+            id,
+            "geojson",
+            &options,
+        )
+        .await
+    }
+
+    /// Add a vector tile source, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::SourceExists` if the ID is taken, or the error MapLibre reported.
+    pub async fn try_add_vector_source(
+        &self,
+        id: &str,
+        options: VectorSourceOptions,
+    ) -> MapResult<()> {
+        self.try_add_source(
+            "try_add_vector_source",
+            || MapCommand::AddVectorSource {
+                id: id.to_string(),
+                options: options.clone(),
+            },
+            id,
+            "vector",
+            &options,
+        )
+        .await
+    }
+
+    /// Add a raster tile source, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::SourceExists` if the ID is taken, or the error MapLibre reported.
+    pub async fn try_add_raster_source(
+        &self,
+        id: &str,
+        options: RasterSourceOptions,
+    ) -> MapResult<()> {
+        self.try_add_source(
+            "try_add_raster_source",
+            || MapCommand::AddRasterSource {
+                id: id.to_string(),
+                options: options.clone(),
+            },
+            id,
+            "raster",
+            &options,
+        )
+        .await
+    }
+
+    /// Add a raster DEM source, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::SourceExists` if the ID is taken, or the error MapLibre reported.
+    pub async fn try_add_raster_dem_source(
+        &self,
+        id: &str,
+        options: RasterDemSourceOptions,
+    ) -> MapResult<()> {
+        self.try_add_source(
+            "try_add_raster_dem_source",
+            || MapCommand::AddRasterDemSource {
+                id: id.to_string(),
+                options: options.clone(),
+            },
+            id,
+            "raster-dem",
+            &options,
+        )
+        .await
+    }
+
+    /// Add an image source, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::SourceExists` if the ID is taken, or the error MapLibre reported.
+    pub async fn try_add_image_source(
+        &self,
+        id: &str,
+        options: ImageSourceOptions,
+    ) -> MapResult<()> {
+        self.try_add_source(
+            "try_add_image_source",
+            || MapCommand::AddImageSource {
+                id: id.to_string(),
+                options: options.clone(),
+            },
+            id,
+            "image",
+            &options,
+        )
+        .await
+    }
+
+    async fn try_add_source(
+        &self,
+        method: &str,
+        command: impl FnOnce() -> MapCommand,
+        id: &str,
+        source_type: &str,
+        options: &(impl serde::Serialize + Sync),
+    ) -> MapResult<()> {
+        self.eval_fallible(method, command, || {
+            let json = serde_json::to_string(options).unwrap_or_default();
+            crate::interop::try_add_source_js(&self.map_id, id, source_type, &json)
+        })
+        .await
+    }
+
+    /// Update the data of an existing GeoJSON source, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::SourceMissing` if no source has this ID.
+    pub async fn try_update_geojson_source(
+        &self,
+        id: &str,
+        data: impl Into<serde_json::Value>,
+    ) -> MapResult<()> {
+        let data = data.into();
+        self.eval_fallible(
+            "try_update_geojson_source",
+            || MapCommand::UpdateGeoJsonSource {
+                id: id.to_string(),
+                data: data.clone(),
+            },
+            || {
+                let json = serde_json::to_string(&data).unwrap_or_default();
+                crate::interop::try_update_geojson_source_js(&self.map_id, id, &json)
+            },
+        )
+        .await
+    }
+
+    /// Remove a source from the map, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::SourceMissing` if no source has this ID, or
+    /// `MapError::InvalidStyleSpec` if a layer still uses it.
+    pub async fn try_remove_source(&self, id: &str) -> MapResult<()> {
+        self.eval_fallible(
+            "try_remove_source",
+            || MapCommand::RemoveSource { id: id.to_string() },
+            || crate::interop::try_remove_source_js(&self.map_id, id),
+        )
+        .await
     }
 }
@@ -1,10 +1,77 @@
 //! Style-related MapHandle methods.
+#![allow(clippy::needless_pass_by_value)]
 
-use super::MapHandle;
+use super::{MapCommand, MapHandle};
+use crate::error::MapResult;
+use crate::options::{MapStyle, SetStyleOptions, StyleSpecification};
 
 impl MapHandle {
-    /// Change the map's style URL
-    pub fn set_style(&self, url: &str) {
-        self.fire_and_forget(|| crate::interop::set_style_js(&self.map_id, url));
+    /// Change the map's style to a style URL or an inline `StyleSpecification`
+    ///
+    /// Runtime sources, layers, images, terrain, sky and fog are replayed onto
+    /// the new style.
+    pub fn set_style(&self, style: impl Into<MapStyle>) {
+        self.set_style_with(style, SetStyleOptions::default());
+    }
+
+    /// Change the map's style, optionally diffing against the current one
+    pub fn set_style_with(&self, style: impl Into<MapStyle>, options: SetStyleOptions) {
+        let style = style.into();
+        self.fire_and_forget(
+            || MapCommand::SetStyle {
+                style: style.clone(),
+                options: options.clone(),
+            },
+            || {
+                if options.diff {
+                    crate::interop::set_style_diff_js(
+                        &self.map_id,
+                        &style,
+                        options.transform_style.as_deref(),
+                    )
+                } else {
+                    crate::interop::set_style_js(&self.map_id, &style)
+                }
+            },
+        );
+    }
+
+    /// Change the map's style, reporting validation failures.
+    ///
+    /// Runtime state is replayed as with `set_style_with`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::InvalidStyleSpec` if MapLibre rejects an inline
+    /// style when the switch starts. Errors in a style fetched from a URL
+    /// arrive later and are not reported.
+    pub async fn try_set_style(
+        &self,
+        style: impl Into<MapStyle>,
+        options: SetStyleOptions,
+    ) -> MapResult<()> {
+        let style = style.into();
+        self.eval_fallible(
+            "try_set_style",
+            || MapCommand::SetStyle {
+                style: style.clone(),
+                options: options.clone(),
+            },
+            || {
+                crate::interop::try_set_style_js(
+                    &self.map_id,
+                    &style,
+                    options.diff,
+                    options.transform_style.as_deref(),
+                )
+            },
+        )
+        .await
+    }
+
+    /// Get the current style document
+    pub async fn get_style(&self) -> Option<StyleSpecification> {
+        self.eval_query("get_style", || crate::interop::get_style_js(&self.map_id))
+            .await
     }
 }
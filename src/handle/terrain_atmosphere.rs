@@ -1,46 +1,116 @@
 //! Terrain, sky, and fog MapHandle methods.
 #![allow(clippy::needless_pass_by_value)]
 
-use super::MapHandle;
+use super::{MapCommand, MapHandle};
+use crate::error::MapResult;
 use crate::options::{FogOptions, SkyOptions, TerrainOptions};
 
 impl MapHandle {
     /// Enable 3D terrain
     pub fn set_terrain(&self, options: TerrainOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options).unwrap_or_default();
-            crate::interop::set_terrain_js(&self.map_id, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::SetTerrain(options.clone()),
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::set_terrain_js(&self.map_id, &json)
+            },
+        );
+    }
+
+    /// Enable 3D terrain, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::SourceMissing` if the raster DEM source does not exist.
+    pub async fn try_set_terrain(&self, options: TerrainOptions) -> MapResult<()> {
+        self.eval_fallible(
+            "try_set_terrain",
+            || MapCommand::SetTerrain(options.clone()),
+            || {
+                let json = serde_json::to_string(&options).unwrap_or_default();
+                crate::interop::try_set_terrain_js(&self.map_id, &json)
+            },
+        )
+        .await
     }
 
     /// Remove 3D terrain
     pub fn remove_terrain(&self) {
-        self.fire_and_forget(|| crate::interop::remove_terrain_js(&self.map_id));
+        self.fire_and_forget(
+            || MapCommand::RemoveTerrain,
+            || crate::interop::remove_terrain_js(&self.map_id),
+        );
     }
 
     /// Set sky properties
     pub fn set_sky(&self, options: SkyOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options.0).unwrap_or_default();
-            crate::interop::set_sky_js(&self.map_id, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::SetSky(options.clone()),
+            || {
+                let json = serde_json::to_string(&options.0).unwrap_or_default();
+                crate::interop::set_sky_js(&self.map_id, &json)
+            },
+        );
+    }
+
+    /// Set sky properties, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::InvalidStyleSpec` if MapLibre rejects the sky specification.
+    pub async fn try_set_sky(&self, options: SkyOptions) -> MapResult<()> {
+        self.eval_fallible(
+            "try_set_sky",
+            || MapCommand::SetSky(options.clone()),
+            || {
+                let json = serde_json::to_string(&options.0).unwrap_or_default();
+                crate::interop::try_set_sky_js(&self.map_id, &json)
+            },
+        )
+        .await
     }
 
     /// Remove sky
     pub fn remove_sky(&self) {
-        self.fire_and_forget(|| crate::interop::remove_sky_js(&self.map_id));
+        self.fire_and_forget(
+            || MapCommand::RemoveSky,
+            || crate::interop::remove_sky_js(&self.map_id),
+        );
     }
 
     /// Set fog/atmosphere properties
     pub fn set_fog(&self, options: FogOptions) {
-        self.fire_and_forget(|| {
-            let json = serde_json::to_string(&options.0).unwrap_or_default();
-            crate::interop::set_fog_js(&self.map_id, &json)
-        });
+        self.fire_and_forget(
+            || MapCommand::SetFog(options.clone()),
+            || {
+                let json = serde_json::to_string(&options.0).unwrap_or_default();
+                crate::interop::set_fog_js(&self.map_id, &json)
+            },
+        );
+    }
+
+    /// Set fog/atmosphere properties, reporting failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MapError::InvalidStyleSpec` if MapLibre rejects the fog specification.
+    pub async fn try_set_fog(&self, options: FogOptions) -> MapResult<()> {
+        self.eval_fallible(
+            "try_set_fog",
+            || MapCommand::SetFog(options.clone()),
+            || {
+                let json = serde_json::to_string(&options.0).unwrap_or_default();
+                crate::interop::try_set_fog_js(&self.map_id, &json)
+            },
+        )
+        .await
     }
 
     /// Remove fog/atmosphere
     pub fn remove_fog(&self) {
-        self.fire_and_forget(|| crate::interop::remove_fog_js(&self.map_id));
+        self.fire_and_forget(
+            || MapCommand::RemoveFog,
+            || crate::interop::remove_fog_js(&self.map_id),
+        );
     }
 }
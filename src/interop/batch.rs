@@ -0,0 +1,43 @@
+//! Command batching JS bridge.
+
+/// Generate JS that runs several command snippets in order within one eval.
+///
+/// Each snippet runs in its own function scope so early `return`s stay local,
+/// and a throwing command is logged without aborting the remaining ones.
+pub fn batch_js(commands: &[String]) -> String {
+    let commands = commands
+        .iter()
+        .map(|command| format!("function() {{ {command} }}"))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    format!(
+        r#"
+        (function() {{
+            const commands = [
+                {commands}
+            ];
+            for (let i = 0; i < commands.length; i += 1) {{
+                try {{
+                    commands[i]();
+                }} catch (err) {{
+                    console.error('[dioxus-maplibre] batched command ' + i + ' failed:', err);
+                }}
+            }}
+        }})();
+        "#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::batch_js;
+
+    #[test]
+    fn batch_js_wraps_commands_in_order() {
+        let js = batch_js(&["first();".to_string(), "return; second();".to_string()]);
+        let first = js.find("function() { first(); }").unwrap();
+        let second = js.find("function() { return; second(); }").unwrap();
+        assert!(first < second);
+        assert!(js.contains("batched command"));
+    }
+}
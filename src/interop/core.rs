@@ -9,8 +9,18 @@ pub fn generate_map_id() -> String {
     format!("map_{}", Uuid::new_v4().to_string().replace('-', ""))
 }
 
+/// Generate a unique DOM element ID with the given prefix.
+pub fn generate_element_id(prefix: &str) -> String {
+    format!("{prefix}_{}", Uuid::new_v4().to_string().replace('-', ""))
+}
+
 /// JS snippet to find a map by ID with fallback to any available map.
 pub(crate) fn find_map_js(map_id: &str) -> String {
+    find_map_or_js(map_id, "return;")
+}
+
+/// JS snippet to find a map by ID, running `on_missing` when no map matches.
+fn find_map_or_js(map_id: &str, on_missing: &str) -> String {
     let map_id_lit = js_single_quoted(map_id);
     format!(
         r#"let mapRegistry = window.__dioxus_maplibre_maps || {{}};
@@ -21,15 +31,70 @@ pub(crate) fn find_map_js(map_id: &str) -> String {
                     map = mapRegistry[mapKeys[0]];
                 }} else {{
                     console.error('[dioxus-maplibre] map id not found', {map_id_lit}, 'available maps:', mapKeys);
-                    return;
+                    {on_missing}
                 }}
             }}"#
     )
 }
 
+/// Wrap a JS body so it reports a `BridgeResult` envelope instead of logging.
+///
+/// The body runs with `map` in scope and may call `fail(kind, fields)` to
+/// report a typed `MapError`, or assign `result` to return a value. MapLibre
+/// validation errors emitted on the map's `error` event are reported as
+/// `invalid_style_spec`, but only while the body runs synchronously: code
+/// after an `await` wraps its MapLibre calls in `collectValidation(() => ...)`
+/// so unrelated errors (e.g. a tile 404) are not blamed on the call.
+pub(crate) fn fallible_map_js(map_id: &str, body: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let find = find_map_or_js(
+        map_id,
+        &format!("return {{ error: {{ kind: 'map_not_found', map_id: {map_id_lit} }} }};"),
+    );
+    format!(
+        r#"
+        {find}
+        const fail = function(kind, fields) {{
+            const err = new Error(kind);
+            err.__dioxusMapError = Object.assign({{ kind }}, fields || {{}});
+            throw err;
+        }};
+        const validationErrors = [];
+        const onValidationError = function(e) {{
+            validationErrors.push(e && e.error && e.error.message
+                ? String(e.error.message)
+                : (e && e.error ? String(e.error) : 'Map error'));
+        }};
+        const collectValidation = function(run) {{
+            map.on('error', onValidationError);
+            try {{
+                return run();
+            }} finally {{
+                map.off('error', onValidationError);
+            }}
+        }};
+        let result = null;
+        try {{
+            await collectValidation(() => (async function() {{
+                {body}
+            }})());
+        }} catch (err) {{
+            if (err && err.__dioxusMapError) {{
+                return {{ error: err.__dioxusMapError }};
+            }}
+            return {{ error: {{ kind: 'js_exception', message: err && err.message ? String(err.message) : String(err) }} }};
+        }}
+        if (validationErrors.length > 0) {{
+            return {{ error: {{ kind: 'invalid_style_spec', message: validationErrors.join('; ') }} }};
+        }}
+        return {{ value: result }};
+        "#
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use super::find_map_js;
+    use super::{fallible_map_js, find_map_js};
 
     #[test]
     fn find_map_js_escapes_map_id_literal() {
@@ -37,4 +102,19 @@ mod tests {
         assert!(js.contains("'map\\'with-quote'"));
         assert!(!js.contains("map'with-quote"));
     }
+
+    #[test]
+    fn fallible_map_js_reports_missing_map() {
+        let js = fallible_map_js("map'1", "map.addSource('a', {});");
+        assert!(js.contains("kind: 'map_not_found', map_id: 'map\\'1'"));
+        assert!(js.contains("map.addSource('a', {});"));
+        assert!(js.contains("return { value: result };"));
+    }
+
+    #[test]
+    fn fallible_map_js_collects_validation_errors_only_synchronously() {
+        let js = fallible_map_js("map1", "map.addSource('a', {});");
+        assert!(js.contains("await collectValidation(() => (async function() {"));
+        assert!(js.contains("map.off('error', onValidationError);"));
+    }
 }
@@ -1,27 +1,52 @@
 //! Feature-state JS bridge.
 
-use super::find_map_js;
 use super::js_escape::js_single_quoted;
+use super::{fallible_map_js, find_map_js};
+use crate::types::FeatureId;
+
+/// Render a feature ID as a JS literal
+fn feature_id_js(feature_id: &FeatureId) -> String {
+    match feature_id {
+        FeatureId::Number(id) => id.to_string(),
+        FeatureId::String(id) => js_single_quoted(id),
+    }
+}
+
+/// Render a MapLibre feature target; without an ID it addresses the whole
+/// source (or source layer).
+fn feature_target_js(
+    source: &str,
+    feature_id: Option<&FeatureId>,
+    source_layer: Option<&str>,
+) -> String {
+    let id_prop = feature_id
+        .map(|id| format!(", id: {}", feature_id_js(id)))
+        .unwrap_or_default();
+    let source_layer_prop = source_layer
+        .map(|layer| format!(", sourceLayer: {}", js_single_quoted(layer)))
+        .unwrap_or_default();
+    format!(
+        "{{ source: {}{id_prop}{source_layer_prop} }}",
+        js_single_quoted(source)
+    )
+}
 
 pub fn set_feature_state_js(
     map_id: &str,
     source: &str,
-    feature_id: i64,
+    feature_id: &FeatureId,
     source_layer: Option<&str>,
     state_json: &str,
 ) -> String {
     let find = find_map_js(map_id);
-    let source_lit = js_single_quoted(source);
-    let source_layer_prop = source_layer
-        .map(|layer| format!(", sourceLayer: {}", js_single_quoted(layer)))
-        .unwrap_or_default();
+    let target = feature_target_js(source, Some(feature_id), source_layer);
     format!(
         r#"
         (function() {{
             {find}
             try {{
                 map.setFeatureState(
-                    {{ source: {source_lit}, id: {feature_id}{source_layer_prop} }},
+                    {target},
                     {state_json}
                 );
             }} catch (err) {{
@@ -36,21 +61,18 @@ pub fn set_feature_state_js(
 pub fn remove_feature_state_js(
     map_id: &str,
     source: &str,
-    feature_id: i64,
+    feature_id: &FeatureId,
     source_layer: Option<&str>,
 ) -> String {
     let find = find_map_js(map_id);
-    let source_lit = js_single_quoted(source);
-    let source_layer_prop = source_layer
-        .map(|layer| format!(", sourceLayer: {}", js_single_quoted(layer)))
-        .unwrap_or_default();
+    let target = feature_target_js(source, Some(feature_id), source_layer);
     format!(
         r#"
         (function() {{
             {find}
             try {{
                 map.removeFeatureState(
-                    {{ source: {source_lit}, id: {feature_id}{source_layer_prop} }}
+                    {target}
                 );
             }} catch (err) {{
                 console.error('[dioxus-maplibre] Failed to remove feature state:', err);
@@ -59,3 +81,142 @@ pub fn remove_feature_state_js(
         "#
     )
 }
+
+/// Generate JS to remove one key from a feature's state
+pub fn remove_feature_state_key_js(
+    map_id: &str,
+    source: &str,
+    feature_id: &FeatureId,
+    source_layer: Option<&str>,
+    key: &str,
+) -> String {
+    let find = find_map_js(map_id);
+    let target = feature_target_js(source, Some(feature_id), source_layer);
+    let key_lit = js_single_quoted(key);
+    format!(
+        r#"
+        (function() {{
+            {find}
+            try {{
+                map.removeFeatureState({target}, {key_lit});
+            }} catch (err) {{
+                console.error('[dioxus-maplibre] Failed to remove feature state key:', err);
+            }}
+        }})();
+        "#
+    )
+}
+
+/// Generate JS to remove the state of every feature in a source (or source layer)
+pub fn clear_feature_states_js(map_id: &str, source: &str, source_layer: Option<&str>) -> String {
+    let find = find_map_js(map_id);
+    let target = feature_target_js(source, None, source_layer);
+    format!(
+        r#"
+        (function() {{
+            {find}
+            try {{
+                map.removeFeatureState({target});
+            }} catch (err) {{
+                console.error('[dioxus-maplibre] Failed to clear feature states:', err);
+            }}
+        }})();
+        "#
+    )
+}
+
+/// Generate JS to set many feature states in one eval
+///
+/// `entries_json` is a JSON array of `[FeatureIdentifier, state]` pairs.
+pub fn set_feature_states_js(map_id: &str, entries_json: &str) -> String {
+    let find = find_map_js(map_id);
+    format!(
+        r#"
+        (function() {{
+            {find}
+            const entries = {entries_json};
+            for (const [feature, state] of entries) {{
+                try {{
+                    map.setFeatureState(feature, state);
+                }} catch (err) {{
+                    console.error('[dioxus-maplibre] Failed to set feature state:', err);
+                }}
+            }}
+        }})();
+        "#
+    )
+}
+
+/// Generate JS to get a feature's state
+pub fn get_feature_state_js(
+    map_id: &str,
+    source: &str,
+    feature_id: &FeatureId,
+    source_layer: Option<&str>,
+) -> String {
+    let find = find_map_js(map_id);
+    let target = feature_target_js(source, Some(feature_id), source_layer);
+    format!(
+        r#"
+        {find}
+        try {{
+            return map.getFeatureState({target}) || {{}};
+        }} catch (err) {{
+            console.error('[dioxus-maplibre] Failed to get feature state:', err);
+            return null;
+        }}
+        "#
+    )
+}
+
+/// Generate fallible JS to set feature state
+pub fn try_set_feature_state_js(
+    map_id: &str,
+    source: &str,
+    feature_id: &FeatureId,
+    source_layer: Option<&str>,
+    state_json: &str,
+) -> String {
+    let source_lit = js_single_quoted(source);
+    let target = feature_target_js(source, Some(feature_id), source_layer);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            if (!map.getSource({source_lit})) {{
+                fail('source_missing', {{ id: {source_lit} }});
+            }}
+            map.setFeatureState(
+                {target},
+                {state_json}
+            );
+            "#
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clear_feature_states_js, remove_feature_state_key_js, set_feature_state_js};
+    use crate::types::FeatureId;
+
+    #[test]
+    fn set_feature_state_js_quotes_string_ids() {
+        let js = set_feature_state_js("map1", "tiles", &FeatureId::from("a'1"), None, "{}");
+        assert!(js.contains("{ source: 'tiles', id: 'a\\'1' }"));
+        let js = set_feature_state_js("map1", "tiles", &FeatureId::from(5), Some("poi"), "{}");
+        assert!(js.contains("{ source: 'tiles', id: 5, sourceLayer: 'poi' }"));
+    }
+
+    #[test]
+    fn remove_feature_state_key_js_passes_key() {
+        let js = remove_feature_state_key_js("map1", "pts", &FeatureId::from(1), None, "hover");
+        assert!(js.contains("map.removeFeatureState({ source: 'pts', id: 1 }, 'hover');"));
+    }
+
+    #[test]
+    fn clear_feature_states_js_omits_id() {
+        let js = clear_feature_states_js("map1", "tiles", Some("poi"));
+        assert!(js.contains("map.removeFeatureState({ source: 'tiles', sourceLayer: 'poi' });"));
+    }
+}
@@ -1,7 +1,54 @@
 //! Image sprite JS bridge.
 
-use super::find_map_js;
 use super::js_escape::js_single_quoted;
+use super::{fallible_map_js, find_map_js};
+
+/// JS helpers turning an image spec (`{ source, options }`) into pixels and
+/// adding it to the map. Shared with the style-switch replay.
+pub(crate) const STYLE_IMAGE_HELPERS_JS: &str = r#"
+            const loadStyleImage = async function(map, spec) {
+                const source = spec.source;
+                if (source.kind === 'url') {
+                    const response = await map.loadImage(source.url);
+                    return response.data;
+                }
+                if (source.kind === 'rgba') {
+                    const binary = atob(source.data);
+                    const data = new Uint8Array(binary.length);
+                    for (let i = 0; i < binary.length; i++) {
+                        data[i] = binary.charCodeAt(i);
+                    }
+                    return { width: source.width, height: source.height, data };
+                }
+                if (source.kind === 'svg') {
+                    const ratio = (spec.options && spec.options.pixelRatio) || 1;
+                    const url = URL.createObjectURL(new Blob([source.svg], { type: 'image/svg+xml' }));
+                    try {
+                        const img = new Image();
+                        img.src = url;
+                        await img.decode();
+                        const canvas = document.createElement('canvas');
+                        canvas.width = Math.max(1, Math.round(img.width * ratio));
+                        canvas.height = Math.max(1, Math.round(img.height * ratio));
+                        const ctx = canvas.getContext('2d');
+                        ctx.drawImage(img, 0, 0, canvas.width, canvas.height);
+                        return ctx.getImageData(0, 0, canvas.width, canvas.height);
+                    } finally {
+                        URL.revokeObjectURL(url);
+                    }
+                }
+                throw new Error('unknown image source kind: ' + source.kind);
+            };
+            const addStyleImage = async function(map, imageId, spec, isCurrent) {
+                const data = await loadStyleImage(map, spec);
+                if (!isCurrent()) {
+                    return;
+                }
+                if (map.hasImage(imageId)) {
+                    map.removeImage(imageId);
+                }
+                map.addImage(imageId, data, spec.options || {});
+            };"#;
 
 pub fn load_image_js(map_id: &str, image_id: &str, url: &str) -> String {
     let find = find_map_js(map_id);
@@ -29,6 +76,36 @@ pub fn load_image_js(map_id: &str, image_id: &str, url: &str) -> String {
     )
 }
 
+/// Generate JS to add (or replace) an image from a spec and track it for replay
+///
+/// `spec_json` is `{ source, options }`, with `source` as built by
+/// `StyleImageSource::to_js_spec`.
+pub fn add_image_js(map_id: &str, image_id: &str, spec_json: &str) -> String {
+    let find = find_map_js(map_id);
+    let map_id_lit = js_single_quoted(map_id);
+    let image_id_lit = js_single_quoted(image_id);
+    format!(
+        r#"
+        (async function() {{
+            {find}
+            {STYLE_IMAGE_HELPERS_JS}
+            const spec = {spec_json};
+            const imageRegistry = window.__dioxus_maplibre_images && window.__dioxus_maplibre_images[{map_id_lit}];
+            if (imageRegistry) {{
+                imageRegistry[{image_id_lit}] = spec;
+            }}
+            // A later add/remove of the same ID supersedes this one
+            const isCurrent = () => !imageRegistry || imageRegistry[{image_id_lit}] === spec;
+            try {{
+                await addStyleImage(map, {image_id_lit}, spec, isCurrent);
+            }} catch (err) {{
+                console.error('[dioxus-maplibre] Failed to add image:', {image_id_lit}, err);
+            }}
+        }})();
+        "#
+    )
+}
+
 /// Generate JS to check if an image exists (returns boolean via dioxus.send)
 pub fn has_image_js(map_id: &str, image_id: &str) -> String {
     let find = find_map_js(map_id);
@@ -88,3 +165,89 @@ pub fn remove_image_js(map_id: &str, image_id: &str) -> String {
         "#
     )
 }
+
+/// Generate fallible JS to load an image and add it to the map's sprite
+///
+/// Reports `image_exists` instead of silently keeping the existing image.
+pub fn try_load_image_js(map_id: &str, image_id: &str, url: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let image_id_lit = js_single_quoted(image_id);
+    let url_lit = js_single_quoted(url);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            if (map.hasImage({image_id_lit})) {{
+                fail('image_exists', {{ id: {image_id_lit} }});
+            }}
+            const response = await map.loadImage({url_lit});
+            collectValidation(() => map.addImage({image_id_lit}, response.data));
+            const imageRegistry = window.__dioxus_maplibre_images && window.__dioxus_maplibre_images[{map_id_lit}];
+            if (imageRegistry) {{
+                imageRegistry[{image_id_lit}] = {url_lit};
+            }}
+            "#
+        ),
+    )
+}
+
+/// Generate fallible JS to add (or replace) an image from a spec
+///
+/// The image is tracked for replay only once MapLibre has accepted it.
+pub fn try_add_image_js(map_id: &str, image_id: &str, spec_json: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let image_id_lit = js_single_quoted(image_id);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            {STYLE_IMAGE_HELPERS_JS}
+            const spec = {spec_json};
+            const data = await loadStyleImage(map, spec);
+            collectValidation(() => {{
+                if (map.hasImage({image_id_lit})) {{
+                    map.removeImage({image_id_lit});
+                }}
+                map.addImage({image_id_lit}, data, spec.options || {{}});
+            }});
+            const imageRegistry = window.__dioxus_maplibre_images && window.__dioxus_maplibre_images[{map_id_lit}];
+            if (imageRegistry && validationErrors.length === 0) {{
+                imageRegistry[{image_id_lit}] = spec;
+            }}
+            "#
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_image_js, try_add_image_js, try_load_image_js};
+
+    #[test]
+    fn try_load_image_js_reports_existing_image() {
+        let js = try_load_image_js("map1", "pin", "https://example.com/pin.png");
+        assert!(js.contains("fail('image_exists', { id: 'pin' });"));
+        assert!(js.contains("collectValidation(() => map.addImage('pin', response.data));"));
+    }
+
+    #[test]
+    fn add_image_js_registers_spec_for_replay() {
+        let js = add_image_js("map1", "pin", r#"{"source":{"kind":"svg","svg":"<svg/>"}}"#);
+        assert!(js.contains("imageRegistry['pin'] = spec;"));
+        assert!(js.contains("await addStyleImage(map, 'pin', spec, isCurrent);"));
+        assert!(js.contains("source.kind === 'svg'"));
+        assert!(js.contains("const binary = atob(source.data);"));
+    }
+
+    #[test]
+    fn try_add_image_js_collects_validation_around_add_image() {
+        let js = try_add_image_js(
+            "map1",
+            "pin",
+            r#"{"source":{"kind":"url","url":"pin.png"}}"#,
+        );
+        assert!(js.contains("const data = await loadStyleImage(map, spec);"));
+        assert!(js.contains("collectValidation(() => {"));
+        assert!(js.contains("map.addImage('pin', data, spec.options || {});"));
+    }
+}
@@ -1,7 +1,57 @@
 //! Layer management JS bridge.
 
-use super::find_map_js;
 use super::js_escape::js_single_quoted;
+use super::{fallible_map_js, find_map_js};
+
+/// JS helpers adding a layer at its `beforeId`/`slot` anchor. Shared with the
+/// style-switch replay and diff.
+pub(crate) const LAYER_ANCHOR_HELPERS_JS: &str = r#"
+            const layerSpec = function(layerDef) {
+                const { beforeId, slot, ...spec } = layerDef;
+                return JSON.parse(JSON.stringify(spec));
+            };
+            const isRoadLayer = function(layer) {
+                if (layer.type !== 'line') {
+                    return false;
+                }
+                return /^(transportation|roads?)$/.test(layer['source-layer'] || '')
+                    || /road|street|highway|motorway|bridge|tunnel/.test(layer.id);
+            };
+            // ID of the layer to insert `layerDef` below in `layers`, or undefined for the top
+            const resolveLayerAnchor = function(layers, layerDef, isManaged) {
+                if (layerDef.beforeId != null && layers.some((layer) => layer.id === layerDef.beforeId)) {
+                    return layerDef.beforeId;
+                }
+                if (!layerDef.slot) {
+                    return undefined;
+                }
+                const basemap = layers.filter((layer) => layer.id !== layerDef.id && !isManaged(layer.id));
+                const firstLabel = basemap.find((layer) => layer.type === 'symbol');
+                const belowLabels = firstLabel ? firstLabel.id : undefined;
+                if (layerDef.slot === 'bottom') {
+                    const first = basemap.find((layer) => layer.type !== 'background');
+                    return first ? first.id : undefined;
+                }
+                if (layerDef.slot === 'below-roads') {
+                    const road = basemap.find(isRoadLayer);
+                    return road ? road.id : belowLabels;
+                }
+                if (layerDef.slot === 'above-roads') {
+                    const lastRoad = basemap.findLastIndex(isRoadLayer);
+                    if (lastRoad < 0) {
+                        return belowLabels;
+                    }
+                    const next = basemap[lastRoad + 1];
+                    return next ? next.id : undefined;
+                }
+                return belowLabels;
+            };
+            const addAnchoredLayer = function(map, layerDef, layerRegistry) {
+                const beforeId = layerDef.beforeId != null || layerDef.slot != null
+                    ? resolveLayerAnchor(map.getStyle().layers, layerDef, (id) => !!(layerRegistry && layerRegistry[id]))
+                    : undefined;
+                map.addLayer(layerSpec(layerDef), beforeId);
+            };"#;
 
 /// Generate JS to add a layer from serialized LayerOptions
 pub fn add_layer_js(map_id: &str, layer_json: &str) -> String {
@@ -11,6 +61,7 @@ pub fn add_layer_js(map_id: &str, layer_json: &str) -> String {
         r#"
         (function() {{
             {find}
+            {LAYER_ANCHOR_HELPERS_JS}
             try {{
                 const layerDef = {layer_json};
                 const layerRegistry = window.__dioxus_maplibre_layers && window.__dioxus_maplibre_layers[{map_id_lit}];
@@ -22,7 +73,7 @@ pub fn add_layer_js(map_id: &str, layer_json: &str) -> String {
                     layerOrder.push(layerDef.id);
                 }}
                 if (map.getLayer(layerDef.id)) return;
-                map.addLayer(layerDef);
+                addAnchoredLayer(map, layerDef, layerRegistry);
             }} catch (err) {{
                 console.error('[dioxus-maplibre] Failed to add layer:', err);
             }}
@@ -31,35 +82,28 @@ pub fn add_layer_js(map_id: &str, layer_json: &str) -> String {
     )
 }
 
-/// Generate JS to remove a layer
-pub fn remove_layer_js(map_id: &str, layer_id: &str) -> String {
-    let find = find_map_js(map_id);
-    let map_id_lit = js_single_quoted(map_id);
-    let layer_id_lit = js_single_quoted(layer_id);
+/// JS snippet detaching all bridge event handlers registered for a layer.
+fn off_layer_handlers_js(map_id_lit: &str, layer_id_lit: &str) -> String {
     format!(
-        r#"
-        (function() {{
-            {find}
-            try {{
-                const mapHandlers = window.__dioxus_maplibre_layer_handlers && window.__dioxus_maplibre_layer_handlers[{map_id_lit}];
+        r#"const mapHandlers = window.__dioxus_maplibre_layer_handlers && window.__dioxus_maplibre_layer_handlers[{map_id_lit}];
                 const handlers = mapHandlers && mapHandlers[{layer_id_lit}];
                 if (handlers) {{
-                    if (handlers.click) {{
-                        map.off('click', {layer_id_lit}, handlers.click);
-                    }}
-                    if (handlers.mouseenter) {{
-                        map.off('mouseenter', {layer_id_lit}, handlers.mouseenter);
-                    }}
-                    if (handlers.mouseleave) {{
-                        map.off('mouseleave', {layer_id_lit}, handlers.mouseleave);
+                    // Handlers are keyed by MapLibre event name.
+                    for (const [eventName, handler] of Object.entries(handlers)) {{
+                        if (typeof handler.clearHover === 'function') {{
+                            handler.clearHover();
+                        }}
+                        map.off(eventName, {layer_id_lit}, handler);
                     }}
                     delete mapHandlers[{layer_id_lit}];
-                }}
+                }}"#
+    )
+}
 
-                if (map.getLayer({layer_id_lit})) {{
-                    map.removeLayer({layer_id_lit});
-                }}
-                const layerRegistry = window.__dioxus_maplibre_layers && window.__dioxus_maplibre_layers[{map_id_lit}];
+/// JS snippet dropping a layer from the runtime registry and replay order.
+fn forget_layer_js(map_id_lit: &str, layer_id_lit: &str) -> String {
+    format!(
+        r#"const layerRegistry = window.__dioxus_maplibre_layers && window.__dioxus_maplibre_layers[{map_id_lit}];
                 if (layerRegistry) {{
                     delete layerRegistry[{layer_id_lit}];
                 }}
@@ -69,7 +113,63 @@ pub fn remove_layer_js(map_id: &str, layer_id: &str) -> String {
                     if (idx >= 0) {{
                         layerOrder.splice(idx, 1);
                     }}
+                }}"#
+    )
+}
+
+/// JS snippet recording a moved layer's explicit position for style replays.
+fn record_layer_move_js(map_id_lit: &str, layer_id_lit: &str, before_id_lit: &str) -> String {
+    format!(
+        r#"// Replays use the explicit position from now on
+                const layerRegistry = window.__dioxus_maplibre_layers && window.__dioxus_maplibre_layers[{map_id_lit}];
+                const layerDef = layerRegistry && layerRegistry[{layer_id_lit}];
+                if (layerDef) {{
+                    delete layerDef.slot;
+                    if ({before_id_lit} != null) {{
+                        layerDef.beforeId = {before_id_lit};
+                    }} else {{
+                        delete layerDef.beforeId;
+                    }}
+                }}
+                const layerOrder = window.__dioxus_maplibre_layer_order && window.__dioxus_maplibre_layer_order[{map_id_lit}];
+                if (layerOrder) {{
+                    const idx = layerOrder.indexOf({layer_id_lit});
+                    if (idx >= 0) {{
+                        layerOrder.splice(idx, 1);
+                    }}
+                    const beforeId = {before_id_lit};
+                    if (beforeId != null) {{
+                        const beforeIdx = layerOrder.indexOf(beforeId);
+                        if (beforeIdx >= 0) {{
+                            layerOrder.splice(beforeIdx, 0, {layer_id_lit});
+                        }} else {{
+                            layerOrder.push({layer_id_lit});
+                        }}
+                    }} else {{
+                        layerOrder.push({layer_id_lit});
+                    }}
+                }}"#
+    )
+}
+
+/// Generate JS to remove a layer
+pub fn remove_layer_js(map_id: &str, layer_id: &str) -> String {
+    let find = find_map_js(map_id);
+    let map_id_lit = js_single_quoted(map_id);
+    let layer_id_lit = js_single_quoted(layer_id);
+    let off_handlers = off_layer_handlers_js(&map_id_lit, &layer_id_lit);
+    let forget_layer = forget_layer_js(&map_id_lit, &layer_id_lit);
+    format!(
+        r#"
+        (function() {{
+            {find}
+            try {{
+                {off_handlers}
+
+                if (map.getLayer({layer_id_lit})) {{
+                    map.removeLayer({layer_id_lit});
                 }}
+                {forget_layer}
             }} catch (err) {{
                 console.error('[dioxus-maplibre] Failed to remove layer:', err);
             }}
@@ -166,15 +266,66 @@ pub fn set_filter_js(map_id: &str, layer_id: &str, filter_json: &str) -> String
     )
 }
 
-/// Generate JS to register a click handler on a layer (separate from layer creation)
-pub fn register_layer_click_js(map_id: &str, layer_id: &str) -> String {
+/// JS helpers shared by layer event bridges: `serializeFeatures` matches
+/// `QueryFeature`, `modifierKeys` matches `ModifierKeys`.
+///
+/// Geometry is only serialized when asked for (click-type events), and a
+/// feature whose geometry cannot be read is dropped instead of the event.
+const LAYER_EVENT_HELPERS_JS: &str = r#"
+            const featureId = function(f) {
+                if (!f || f.id === undefined || f.id === null) {
+                    return null;
+                }
+                if (typeof f.id === 'string') {
+                    return f.id;
+                }
+                return Number.isFinite(f.id) ? Math.trunc(f.id) : null;
+            };
+            const serializeFeatures = function(features, withGeometry) {
+                const out = [];
+                for (const f of features || []) {
+                    try {
+                        out.push({
+                            id: featureId(f),
+                            geometry: withGeometry ? f.geometry : null,
+                            properties: f.properties || {},
+                            source: f.source,
+                            sourceLayer: f.sourceLayer || null,
+                            state: f.state || {}
+                        });
+                    } catch (err) {
+                        console.warn('[dioxus-maplibre] Skipping unreadable feature:', err);
+                    }
+                }
+                return out;
+            };
+            const modifierKeys = function(original) {
+                return {
+                    alt: !!(original && original.altKey),
+                    ctrl: !!(original && original.ctrlKey),
+                    meta: !!(original && original.metaKey),
+                    shift: !!(original && original.shiftKey)
+                };
+            };"#;
+
+/// Generate JS forwarding a pointer event on a layer's features to Rust as
+/// `bridge_type`, with the same payload as `layer_click`.
+fn register_layer_pointer_js(
+    map_id: &str,
+    layer_id: &str,
+    event_name: &str,
+    bridge_type: &str,
+) -> String {
     let find = find_map_js(map_id);
     let map_id_lit = js_single_quoted(map_id);
     let layer_id_lit = js_single_quoted(layer_id);
+    let event_lit = js_single_quoted(event_name);
+    let bridge_type_lit = js_single_quoted(bridge_type);
     format!(
         r#"
         (function() {{
             {find}
+            {LAYER_EVENT_HELPERS_JS}
             const handlersRoot = window.__dioxus_maplibre_layer_handlers;
             if (!handlersRoot) {{
                 return;
@@ -187,48 +338,56 @@ pub fn register_layer_click_js(map_id: &str, layer_id: &str) -> String {
                 mapHandlers[{layer_id_lit}] = {{}};
             }}
             const handlers = mapHandlers[{layer_id_lit}];
-            if (handlers.click) {{
+            if (handlers[{event_lit}]) {{
                 return;
             }}
-            const onClick = function(e) {{
+            const onEvent = function(e) {{
+                // Pointer events on RSX marker content bubble through for Dioxus handlers.
+                if (e.originalEvent && e.originalEvent.__dioxusMarkerClick) {{
+                    return;
+                }}
                 if (e.features && e.features.length > 0) {{
                     e.originalEvent.stopPropagation();
                     const feature = e.features[0];
                     if (window.__dioxus_maplibre_sendEvent) {{
                         window.__dioxus_maplibre_sendEvent(JSON.stringify({{
-                            type: 'layer_click',
+                            type: {bridge_type_lit},
                             layer_id: {layer_id_lit},
-                            feature_id: feature.id !== undefined ? feature.id : null,
+                            feature_id: featureId(feature),
                             properties: feature.properties || {{}},
-                            latlng: {{ lat: e.lngLat.lat, lng: e.lngLat.lng }}
+                            latlng: {{ lat: e.lngLat.lat, lng: e.lngLat.lng }},
+                            point: {{ x: e.point.x, y: e.point.y }},
+                            modifiers: modifierKeys(e.originalEvent),
+                            features: serializeFeatures(e.features, true)
                         }}));
                     }}
                 }}
             }};
-            handlers.click = onClick;
-            map.on('click', {layer_id_lit}, onClick);
+            handlers[{event_lit}] = onEvent;
+            map.on({event_lit}, {layer_id_lit}, onEvent);
         }})();
         "#
     )
 }
 
-/// Generate JS to unregister click handlers on a layer
-pub fn unregister_layer_click_js(map_id: &str, layer_id: &str) -> String {
+/// Generate JS detaching the bridge handler for one event on a layer
+fn unregister_layer_event_js(map_id: &str, layer_id: &str, event_name: &str) -> String {
     let find = find_map_js(map_id);
     let map_id_lit = js_single_quoted(map_id);
     let layer_id_lit = js_single_quoted(layer_id);
+    let event_lit = js_single_quoted(event_name);
     format!(
         r#"
         (function() {{
             {find}
             const mapHandlers = window.__dioxus_maplibre_layer_handlers && window.__dioxus_maplibre_layer_handlers[{map_id_lit}];
             const handlers = mapHandlers && mapHandlers[{layer_id_lit}];
-            if (!handlers || !handlers.click) {{
+            if (!handlers || !handlers[{event_lit}]) {{
                 return;
             }}
-            map.off('click', {layer_id_lit}, handlers.click);
-            delete handlers.click;
-            if (!handlers.mouseenter && !handlers.mouseleave) {{
+            map.off({event_lit}, {layer_id_lit}, handlers[{event_lit}]);
+            delete handlers[{event_lit}];
+            if (Object.keys(handlers).length === 0) {{
                 delete mapHandlers[{layer_id_lit}];
             }}
         }})();
@@ -236,8 +395,42 @@ pub fn unregister_layer_click_js(map_id: &str, layer_id: &str) -> String {
     )
 }
 
+/// Generate JS to register a click handler on a layer (separate from layer creation)
+pub fn register_layer_click_js(map_id: &str, layer_id: &str) -> String {
+    register_layer_pointer_js(map_id, layer_id, "click", "layer_click")
+}
+
+/// Generate JS to unregister click handlers on a layer
+pub fn unregister_layer_click_js(map_id: &str, layer_id: &str) -> String {
+    unregister_layer_event_js(map_id, layer_id, "click")
+}
+
+/// Generate JS to register a double-click handler on a layer
+pub fn register_layer_dblclick_js(map_id: &str, layer_id: &str) -> String {
+    register_layer_pointer_js(map_id, layer_id, "dblclick", "layer_dblclick")
+}
+
+/// Generate JS to unregister the double-click handler on a layer
+pub fn unregister_layer_dblclick_js(map_id: &str, layer_id: &str) -> String {
+    unregister_layer_event_js(map_id, layer_id, "dblclick")
+}
+
+/// Generate JS to register a context menu (right-click) handler on a layer
+pub fn register_layer_contextmenu_js(map_id: &str, layer_id: &str) -> String {
+    register_layer_pointer_js(map_id, layer_id, "contextmenu", "layer_contextmenu")
+}
+
+/// Generate JS to unregister the context menu handler on a layer
+pub fn unregister_layer_contextmenu_js(map_id: &str, layer_id: &str) -> String {
+    unregister_layer_event_js(map_id, layer_id, "contextmenu")
+}
+
 /// Generate JS to register hover handlers on a layer (separate from layer creation)
-pub fn register_layer_hover_js(map_id: &str, layer_id: &str) -> String {
+///
+/// Tracks the topmost feature under the cursor via `mousemove`, emitting
+/// `enter`, `change` and `leave` phases. With `feature_state`, a `hover`
+/// feature-state follows the hovered feature (features need IDs).
+pub fn register_layer_hover_js(map_id: &str, layer_id: &str, feature_state: bool) -> String {
     let find = find_map_js(map_id);
     let map_id_lit = js_single_quoted(map_id);
     let layer_id_lit = js_single_quoted(layer_id);
@@ -245,6 +438,7 @@ pub fn register_layer_hover_js(map_id: &str, layer_id: &str) -> String {
         r#"
         (function() {{
             {find}
+            {LAYER_EVENT_HELPERS_JS}
             const handlersRoot = window.__dioxus_maplibre_layer_handlers;
             if (!handlersRoot) {{
                 return;
@@ -257,47 +451,85 @@ pub fn register_layer_hover_js(map_id: &str, layer_id: &str) -> String {
                 mapHandlers[{layer_id_lit}] = {{}};
             }}
             const handlers = mapHandlers[{layer_id_lit}];
-
-            if (!handlers.mouseenter) {{
-                const onMouseEnter = function(e) {{
-                    map.getCanvas().style.cursor = 'pointer';
-                    if (e.features && e.features.length > 0) {{
-                        const feature = e.features[0];
-                        if (window.__dioxus_maplibre_sendEvent) {{
-                            window.__dioxus_maplibre_sendEvent(JSON.stringify({{
-                                type: 'layer_hover',
-                                layer_id: {layer_id_lit},
-                                feature_id: feature.id !== undefined ? feature.id : null,
-                                properties: feature.properties || {{}},
-                                latlng: {{ lat: e.lngLat.lat, lng: e.lngLat.lng }},
-                                cursor_x: e.originalEvent.clientX,
-                                cursor_y: e.originalEvent.clientY
-                            }}));
-                        }}
-                    }}
-                }};
-                handlers.mouseenter = onMouseEnter;
-                map.on('mouseenter', {layer_id_lit}, onMouseEnter);
+            if (handlers.mousemove) {{
+                return;
             }}
 
-            if (!handlers.mouseleave) {{
-                const onMouseLeave = function() {{
-                    map.getCanvas().style.cursor = '';
-                    if (window.__dioxus_maplibre_sendEvent) {{
-                        window.__dioxus_maplibre_sendEvent(JSON.stringify({{
-                            type: 'layer_hover',
-                            layer_id: {layer_id_lit},
-                            feature_id: null,
-                            properties: null,
-                            latlng: {{ lat: 0, lng: 0 }},
-                            cursor_x: 0,
-                            cursor_y: 0
-                        }}));
-                    }}
-                }};
-                handlers.mouseleave = onMouseLeave;
-                map.on('mouseleave', {layer_id_lit}, onMouseLeave);
-            }}
+            const useFeatureState = {feature_state};
+            let hovered = null;
+
+            const featureKey = function(f) {{
+                const id = f.id !== undefined ? f.id : JSON.stringify(f.properties || {{}});
+                return [f.source, f.sourceLayer || '', id].join('|');
+            }};
+            const setHoverState = function(target, value) {{
+                if (!useFeatureState || target.id === undefined) {{
+                    return;
+                }}
+                try {{
+                    map.setFeatureState(target, {{ hover: value }});
+                }} catch (_err) {{}}
+            }};
+            const send = function(phase, e, feature) {{
+                if (!window.__dioxus_maplibre_sendEvent) {{
+                    return;
+                }}
+                const original = e.originalEvent;
+                window.__dioxus_maplibre_sendEvent(JSON.stringify({{
+                    type: 'layer_hover',
+                    layer_id: {layer_id_lit},
+                    phase,
+                    feature_id: featureId(feature),
+                    properties: feature ? (feature.properties || {{}}) : null,
+                    latlng: {{ lat: e.lngLat.lat, lng: e.lngLat.lng }},
+                    cursor_x: original ? original.clientX : 0,
+                    cursor_y: original ? original.clientY : 0,
+                    point: {{ x: e.point.x, y: e.point.y }},
+                    modifiers: modifierKeys(original),
+                    features: feature ? serializeFeatures(e.features, false) : []
+                }}));
+            }};
+
+            const onMouseMove = function(e) {{
+                const feature = e.features && e.features[0];
+                if (!feature) {{
+                    return;
+                }}
+                const key = featureKey(feature);
+                if (hovered && hovered.key === key) {{
+                    return;
+                }}
+                const phase = hovered ? 'change' : 'enter';
+                if (hovered) {{
+                    setHoverState(hovered.target, false);
+                }}
+                const target = {{ source: feature.source, id: feature.id }};
+                if (feature.sourceLayer) {{
+                    target.sourceLayer = feature.sourceLayer;
+                }}
+                hovered = {{ key, target }};
+                setHoverState(target, true);
+                map.getCanvas().style.cursor = 'pointer';
+                send(phase, e, feature);
+            }};
+            // Called on leave and when the bridge is detached.
+            onMouseMove.clearHover = function() {{
+                if (hovered) {{
+                    setHoverState(hovered.target, false);
+                    hovered = null;
+                }}
+            }};
+
+            const onMouseLeave = function(e) {{
+                onMouseMove.clearHover();
+                map.getCanvas().style.cursor = '';
+                send('leave', e, null);
+            }};
+
+            handlers.mousemove = onMouseMove;
+            handlers.mouseleave = onMouseLeave;
+            map.on('mousemove', {layer_id_lit}, onMouseMove);
+            map.on('mouseleave', {layer_id_lit}, onMouseLeave);
         }})();
         "#
     )
@@ -317,15 +549,17 @@ pub fn unregister_layer_hover_js(map_id: &str, layer_id: &str) -> String {
             if (!handlers) {{
                 return;
             }}
-            if (handlers.mouseenter) {{
-                map.off('mouseenter', {layer_id_lit}, handlers.mouseenter);
-                delete handlers.mouseenter;
+            if (handlers.mousemove) {{
+                handlers.mousemove.clearHover();
+                map.off('mousemove', {layer_id_lit}, handlers.mousemove);
+                delete handlers.mousemove;
             }}
             if (handlers.mouseleave) {{
                 map.off('mouseleave', {layer_id_lit}, handlers.mouseleave);
                 delete handlers.mouseleave;
+                map.getCanvas().style.cursor = '';
             }}
-            if (!handlers.click && !handlers.mouseenter && !handlers.mouseleave) {{
+            if (Object.keys(handlers).length === 0) {{
                 delete mapHandlers[{layer_id_lit}];
             }}
         }})();
@@ -340,6 +574,7 @@ pub fn move_layer_js(map_id: &str, layer_id: &str, before_id: Option<&str>) -> S
     let layer_id_lit = js_single_quoted(layer_id);
     let before_arg = before_id.map_or_else(|| "undefined".to_string(), js_single_quoted);
     let before_id_lit = before_id.map_or_else(|| "null".to_string(), js_single_quoted);
+    let record_move = record_layer_move_js(&map_id_lit, &layer_id_lit, &before_id_lit);
     format!(
         r#"
         (function() {{
@@ -348,24 +583,7 @@ pub fn move_layer_js(map_id: &str, layer_id: &str, before_id: Option<&str>) -> S
                 if (map.getLayer({layer_id_lit})) {{
                     map.moveLayer({layer_id_lit}, {before_arg});
                 }}
-                const layerOrder = window.__dioxus_maplibre_layer_order && window.__dioxus_maplibre_layer_order[{map_id_lit}];
-                if (layerOrder) {{
-                    const idx = layerOrder.indexOf({layer_id_lit});
-                    if (idx >= 0) {{
-                        layerOrder.splice(idx, 1);
-                    }}
-                    const beforeId = {before_id_lit};
-                    if (beforeId != null) {{
-                        const beforeIdx = layerOrder.indexOf(beforeId);
-                        if (beforeIdx >= 0) {{
-                            layerOrder.splice(beforeIdx, 0, {layer_id_lit});
-                        }} else {{
-                            layerOrder.push({layer_id_lit});
-                        }}
-                    }} else {{
-                        layerOrder.push({layer_id_lit});
-                    }}
-                }}
+                {record_move}
             }} catch (err) {{
                 console.error('[dioxus-maplibre] Failed to move layer:', err);
             }}
@@ -373,3 +591,243 @@ pub fn move_layer_js(map_id: &str, layer_id: &str, before_id: Option<&str>) -> S
         "#
     )
 }
+
+/// Generate fallible JS to add a layer from serialized LayerOptions
+///
+/// Reports `layer_exists` and `source_missing` up front, since MapLibre only
+/// emits those as `error` events.
+pub fn try_add_layer_js(map_id: &str, layer_json: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            {LAYER_ANCHOR_HELPERS_JS}
+            const layerDef = {layer_json};
+            if (map.getLayer(layerDef.id)) {{
+                fail('layer_exists', {{ id: layerDef.id }});
+            }}
+            if (typeof layerDef.source === 'string' && !map.getSource(layerDef.source)) {{
+                fail('source_missing', {{ id: layerDef.source }});
+            }}
+            const layerRegistry = window.__dioxus_maplibre_layers && window.__dioxus_maplibre_layers[{map_id_lit}];
+            addAnchoredLayer(map, layerDef, layerRegistry);
+            if (map.getLayer(layerDef.id)) {{
+                const layerOrder = window.__dioxus_maplibre_layer_order && window.__dioxus_maplibre_layer_order[{map_id_lit}];
+                if (layerRegistry) {{
+                    layerRegistry[layerDef.id] = JSON.parse(JSON.stringify(layerDef));
+                }}
+                if (layerOrder && !layerOrder.includes(layerDef.id)) {{
+                    layerOrder.push(layerDef.id);
+                }}
+            }}
+            "#
+        ),
+    )
+}
+
+/// Generate fallible JS to remove a layer
+pub fn try_remove_layer_js(map_id: &str, layer_id: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let layer_id_lit = js_single_quoted(layer_id);
+    let off_handlers = off_layer_handlers_js(&map_id_lit, &layer_id_lit);
+    let forget_layer = forget_layer_js(&map_id_lit, &layer_id_lit);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            if (!map.getLayer({layer_id_lit})) {{
+                fail('layer_missing', {{ id: {layer_id_lit} }});
+            }}
+            {off_handlers}
+            map.removeLayer({layer_id_lit});
+            {forget_layer}
+            "#
+        ),
+    )
+}
+
+/// Generate fallible JS to move a layer before another (or to the top)
+pub fn try_move_layer_js(map_id: &str, layer_id: &str, before_id: Option<&str>) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let layer_id_lit = js_single_quoted(layer_id);
+    let before_arg = before_id.map_or_else(|| "undefined".to_string(), js_single_quoted);
+    let before_id_lit = before_id.map_or_else(|| "null".to_string(), js_single_quoted);
+    let record_move = record_layer_move_js(&map_id_lit, &layer_id_lit, &before_id_lit);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            if (!map.getLayer({layer_id_lit})) {{
+                fail('layer_missing', {{ id: {layer_id_lit} }});
+            }}
+            if ({before_id_lit} != null && !map.getLayer({before_id_lit})) {{
+                fail('layer_missing', {{ id: {before_id_lit} }});
+            }}
+            map.moveLayer({layer_id_lit}, {before_arg});
+            {record_move}
+            "#
+        ),
+    )
+}
+
+/// Generate fallible JS to set a paint or layout property on a layer
+///
+/// `kind` is either `paint` or `layout`.
+fn try_set_layer_property_js(
+    map_id: &str,
+    layer_id: &str,
+    kind: &str,
+    name: &str,
+    value_json: &str,
+) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let layer_id_lit = js_single_quoted(layer_id);
+    let name_lit = js_single_quoted(name);
+    let setter = if kind == "paint" {
+        "setPaintProperty"
+    } else {
+        "setLayoutProperty"
+    };
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            if (!map.getLayer({layer_id_lit})) {{
+                fail('layer_missing', {{ id: {layer_id_lit} }});
+            }}
+            map.{setter}({layer_id_lit}, {name_lit}, {value_json});
+            const layerRegistry = window.__dioxus_maplibre_layers && window.__dioxus_maplibre_layers[{map_id_lit}];
+            if (layerRegistry && layerRegistry[{layer_id_lit}] && validationErrors.length === 0) {{
+                if (!layerRegistry[{layer_id_lit}].{kind}) {{
+                    layerRegistry[{layer_id_lit}].{kind} = {{}};
+                }}
+                layerRegistry[{layer_id_lit}].{kind}[{name_lit}] = {value_json};
+            }}
+            "#
+        ),
+    )
+}
+
+/// Generate fallible JS to set a paint property on a layer
+pub fn try_set_paint_property_js(
+    map_id: &str,
+    layer_id: &str,
+    name: &str,
+    value_json: &str,
+) -> String {
+    try_set_layer_property_js(map_id, layer_id, "paint", name, value_json)
+}
+
+/// Generate fallible JS to set a layout property on a layer
+pub fn try_set_layout_property_js(
+    map_id: &str,
+    layer_id: &str,
+    name: &str,
+    value_json: &str,
+) -> String {
+    try_set_layer_property_js(map_id, layer_id, "layout", name, value_json)
+}
+
+/// Generate fallible JS to set a filter on a layer
+pub fn try_set_filter_js(map_id: &str, layer_id: &str, filter_json: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let layer_id_lit = js_single_quoted(layer_id);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            if (!map.getLayer({layer_id_lit})) {{
+                fail('layer_missing', {{ id: {layer_id_lit} }});
+            }}
+            map.setFilter({layer_id_lit}, {filter_json});
+            const layerRegistry = window.__dioxus_maplibre_layers && window.__dioxus_maplibre_layers[{map_id_lit}];
+            if (layerRegistry && layerRegistry[{layer_id_lit}] && validationErrors.length === 0) {{
+                layerRegistry[{layer_id_lit}].filter = {filter_json};
+            }}
+            "#
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        add_layer_js, move_layer_js, register_layer_dblclick_js, register_layer_hover_js,
+        remove_layer_js, try_add_layer_js, try_move_layer_js, try_set_paint_property_js,
+    };
+
+    #[test]
+    fn add_layer_js_resolves_anchor_and_strips_it_from_spec() {
+        let js = add_layer_js("map1", r#"{"id":"a","type":"fill","slot":"below-labels"}"#);
+        assert!(js.contains("addAnchoredLayer(map, layerDef, layerRegistry);"));
+        assert!(js.contains("const { beforeId, slot, ...spec } = layerDef;"));
+        assert!(js.contains("map.addLayer(layerSpec(layerDef), beforeId);"));
+    }
+
+    #[test]
+    fn move_layer_js_records_explicit_position() {
+        let js = move_layer_js("map1", "a", Some("b"));
+        assert!(js.contains("map.moveLayer('a', 'b');"));
+        assert!(js.contains("layerDef.beforeId = 'b';"));
+        assert!(js.contains("delete layerDef.slot;"));
+    }
+
+    #[test]
+    fn try_move_layer_js_checks_both_layers() {
+        let js = try_move_layer_js("map1", "a", Some("b"));
+        assert!(js.contains("fail('layer_missing', { id: 'a' });"));
+        assert!(js.contains("fail('layer_missing', { id: 'b' });"));
+        assert!(js.contains("map.moveLayer('a', 'b');"));
+        assert!(js.contains("layerDef.beforeId = 'b';"));
+    }
+
+    #[test]
+    fn remove_layer_js_detaches_handlers_and_forgets_layer() {
+        let js = remove_layer_js("map1", "layer'1");
+        assert!(js.contains("map.off(eventName, 'layer\\'1', handler);"));
+        assert!(js.contains("delete layerRegistry['layer\\'1'];"));
+    }
+
+    #[test]
+    fn register_layer_dblclick_js_forwards_as_layer_dblclick() {
+        let js = register_layer_dblclick_js("map1", "parks");
+        assert!(js.contains("map.on('dblclick', 'parks', onEvent);"));
+        assert!(js.contains("type: 'layer_dblclick',"));
+        assert!(js.contains("features: serializeFeatures(e.features, true)"));
+        assert!(js.contains("if (e.originalEvent && e.originalEvent.__dioxusMarkerClick) {"));
+    }
+
+    #[test]
+    fn register_layer_hover_js_omits_feature_geometry() {
+        let js = register_layer_hover_js("map1", "parks", false);
+        assert!(js.contains("serializeFeatures(e.features, false)"));
+        assert!(js.contains("geometry: withGeometry ? f.geometry : null,"));
+    }
+
+    #[test]
+    fn register_layer_hover_js_tracks_mousemove_and_feature_state() {
+        let js = register_layer_hover_js("map1", "parks", true);
+        assert!(js.contains("const useFeatureState = true;"));
+        assert!(js.contains("map.on('mousemove', 'parks', onMouseMove);"));
+        assert!(js.contains("const phase = hovered ? 'change' : 'enter';"));
+        assert!(
+            register_layer_hover_js("map1", "parks", false)
+                .contains("const useFeatureState = false;")
+        );
+    }
+
+    #[test]
+    fn try_add_layer_js_checks_layer_and_source() {
+        let js = try_add_layer_js("map1", r#"{"id":"a","type":"circle","source":"s"}"#);
+        assert!(js.contains("fail('layer_exists', { id: layerDef.id });"));
+        assert!(js.contains("fail('source_missing', { id: layerDef.source });"));
+    }
+
+    #[test]
+    fn try_set_paint_property_js_checks_layer() {
+        let js = try_set_paint_property_js("map1", "layer1", "circle-color", r#""red""#);
+        assert!(js.contains("fail('layer_missing', { id: 'layer1' });"));
+        assert!(js.contains("map.setPaintProperty('layer1', 'circle-color', \"red\");"));
+    }
+}
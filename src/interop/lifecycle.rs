@@ -1,6 +1,8 @@
 //! Map initialization and teardown JS bridge.
 
 use super::js_escape::js_single_quoted;
+use super::style::style_value_js;
+use crate::options::MapStyle;
 
 /// Generate JS to initialize a MapLibre map.
 ///
@@ -10,7 +12,7 @@ use super::js_escape::js_single_quoted;
 pub fn init_map_js(
     container_id: &str,
     map_id: &str,
-    style: &str,
+    style: &MapStyle,
     center_lng: f64,
     center_lat: f64,
     zoom: f64,
@@ -21,10 +23,11 @@ pub fn init_map_js(
     max_bounds: Option<&str>,
     cooperative_gestures: Option<bool>,
     move_event_throttle_ms: u32,
+    opt_in_events: &[&str],
 ) -> String {
     let container_id_lit = js_single_quoted(container_id);
     let map_id_lit = js_single_quoted(map_id);
-    let style_lit = js_single_quoted(style);
+    let style_value = style_value_js(style);
 
     let min_zoom_param = min_zoom
         .map(|z| format!("minZoom: {z},"))
@@ -38,6 +41,8 @@ pub fn init_map_js(
     let cooperative_gestures_param = cooperative_gestures
         .map(|v| format!("cooperativeGestures: {v},"))
         .unwrap_or_default();
+    let opt_in_events_json =
+        serde_json::to_string(opt_in_events).unwrap_or_else(|_| "[]".to_string());
 
     format!(
         r#"
@@ -143,7 +148,7 @@ pub fn init_map_js(
             try {{
                 const map = new maplibregl.Map({{
                     container,
-                    style: {style_lit},
+                    style: {style_value},
                     center: [{center_lng}, {center_lat}],
                     zoom: {zoom},
                     bearing: {bearing},
@@ -160,6 +165,7 @@ pub fn init_map_js(
                     Number.isFinite(initialMoveEventThrottleMs) && initialMoveEventThrottleMs >= 0
                         ? initialMoveEventThrottleMs
                         : 80;
+                map.__dioxusOptInEvents = new Set({opt_in_events_json});
 
                 // Store map reference under both actual container ID and map_id.
                 window.__dioxus_maplibre_maps[actualContainerId] = map;
@@ -195,7 +201,18 @@ pub fn init_map_js(
 
                 // --- Event listeners ---
 
+                // High-volume events are only forwarded for the types in
+                // `map.__dioxusOptInEvents`, i.e. those with a Rust handler.
+                const eventEnabled = function(type) {{
+                    const enabled = map.__dioxusOptInEvents;
+                    return !!enabled && enabled.has(type);
+                }};
+
                 map.on('click', function(e) {{
+                    // Clicks on RSX marker content bubble through for Dioxus handlers.
+                    if (e.originalEvent && e.originalEvent.__dioxusMarkerClick) {{
+                        return;
+                    }}
                     dioxus.send(JSON.stringify({{
                         type: 'click',
                         latlng: {{ lat: e.lngLat.lat, lng: e.lngLat.lng }},
@@ -204,6 +221,9 @@ pub fn init_map_js(
                 }});
 
                 map.on('dblclick', function(e) {{
+                    if (e.originalEvent && e.originalEvent.__dioxusMarkerClick) {{
+                        return;
+                    }}
                     dioxus.send(JSON.stringify({{
                         type: 'dblclick',
                         latlng: {{ lat: e.lngLat.lat, lng: e.lngLat.lng }},
@@ -212,6 +232,9 @@ pub fn init_map_js(
                 }});
 
                 map.on('contextmenu', function(e) {{
+                    if (e.originalEvent && e.originalEvent.__dioxusMarkerClick) {{
+                        return;
+                    }}
                     dioxus.send(JSON.stringify({{
                         type: 'contextmenu',
                         latlng: {{ lat: e.lngLat.lat, lng: e.lngLat.lng }},
@@ -219,14 +242,32 @@ pub fn init_map_js(
                     }}));
                 }});
 
-                const emitMoveEvent = function(eventName) {{
+                // MapLibre only attaches `originalEvent` for user input.
+                const isUserInitiated = function(e) {{
+                    return !!(e && e.originalEvent);
+                }};
+
+                let moveSeq = 0;
+                const emitMoveEvent = function(eventName, userInitiated) {{
+                    moveSeq += 1;
                     const center = map.getCenter();
                     const bounds = map.getBounds();
+                    const padding = map.getPadding();
                     dioxus.send(JSON.stringify({{
                         type: 'move',
                         phase: eventName,
+                        seq: moveSeq,
+                        user_initiated: userInitiated,
                         center: {{ lat: center.lat, lng: center.lng }},
                         zoom: map.getZoom(),
+                        bearing: map.getBearing(),
+                        pitch: map.getPitch(),
+                        padding: {{
+                            top: padding.top,
+                            bottom: padding.bottom,
+                            left: padding.left,
+                            right: padding.right
+                        }},
                         bounds: {{
                             sw: {{ lat: bounds.getSouth(), lng: bounds.getWest() }},
                             ne: {{ lat: bounds.getNorth(), lng: bounds.getEast() }}
@@ -238,6 +279,7 @@ pub fn init_map_js(
                 let moveRafId = null;
                 let movePending = false;
                 let lastMoveEmitAt = 0;
+                let moveUserInitiated = false;
 
                 const getMoveEventThrottleMs = function() {{
                     const value = Number(map.__dioxusMoveEventThrottleMs);
@@ -267,48 +309,299 @@ pub fn init_map_js(
                             return;
                         }}
                         lastMoveEmitAt = now;
-                        emitMoveEvent('move');
+                        emitMoveEvent('move', moveUserInitiated);
                     }});
                 }};
 
-                map.on('move', function() {{
+                map.on('move', function(e) {{
+                    moveUserInitiated = isUserInitiated(e);
                     movePending = true;
                     scheduleMoveEmit();
                 }});
 
-                map.on('moveend', function() {{
+                map.on('moveend', function(e) {{
                     movePending = false;
                     if (moveRafId !== null) {{
                         cancelAnimationFrame(moveRafId);
                         moveRafId = null;
                     }}
-                    emitMoveEvent('moveend');
+                    emitMoveEvent('moveend', isUserInitiated(e));
                 }});
 
-                map.on('zoomend', function() {{
+                // Pointer events: continuous ones share the move throttle.
+                const modifierKeys = function(original) {{
+                    return {{
+                        alt: !!(original && original.altKey),
+                        ctrl: !!(original && original.ctrlKey),
+                        meta: !!(original && original.metaKey),
+                        shift: !!(original && original.shiftKey)
+                    }};
+                }};
+
+                const mousePayload = function(type, e) {{
+                    const original = e.originalEvent;
+                    return {{
+                        type,
+                        latlng: {{ lat: e.lngLat.lat, lng: e.lngLat.lng }},
+                        point: {{ x: e.point.x, y: e.point.y }},
+                        button: original && typeof original.button === 'number' ? original.button : 0,
+                        modifiers: modifierKeys(original)
+                    }};
+                }};
+
+                const touchPayload = function(type, e) {{
+                    return {{
+                        type,
+                        latlng: {{ lat: e.lngLat.lat, lng: e.lngLat.lng }},
+                        point: {{ x: e.point.x, y: e.point.y }},
+                        latlngs: (e.lngLats || []).map(ll => ({{ lat: ll.lat, lng: ll.lng }})),
+                        points: (e.points || []).map(p => ({{ x: p.x, y: p.y }})),
+                        modifiers: modifierKeys(e.originalEvent)
+                    }};
+                }};
+
+                const wheelPayload = function(e) {{
+                    const original = e.originalEvent;
+                    const rect = map.getCanvasContainer().getBoundingClientRect();
+                    const x = original.clientX - rect.left;
+                    const y = original.clientY - rect.top;
+                    const lngLat = map.unproject([x, y]);
+                    return {{
+                        type: 'wheel',
+                        latlng: {{ lat: lngLat.lat, lng: lngLat.lng }},
+                        point: {{ x, y }},
+                        delta_x: original.deltaX,
+                        delta_y: original.deltaY,
+                        delta_mode: original.deltaMode,
+                        modifiers: modifierKeys(original)
+                    }};
+                }};
+
+                // Keeps only the latest payload (or `merge`s them) and emits it
+                // at most once per animation frame and throttle window.
+                const createThrottledEmitter = function(merge) {{
+                    let pending = null;
+                    let rafId = null;
+                    let lastEmitAt = 0;
+                    const now = function() {{
+                        return (typeof performance !== 'undefined' && performance.now)
+                            ? performance.now()
+                            : Date.now();
+                    }};
+                    const flush = function() {{
+                        if (rafId !== null) {{
+                            cancelAnimationFrame(rafId);
+                            rafId = null;
+                        }}
+                        if (pending === null) {{
+                            return;
+                        }}
+                        const payload = pending;
+                        pending = null;
+                        lastEmitAt = now();
+                        dioxus.send(JSON.stringify(payload));
+                    }};
+                    const schedule = function() {{
+                        if (rafId !== null) {{
+                            return;
+                        }}
+                        rafId = requestAnimationFrame(() => {{
+                            rafId = null;
+                            if (pending === null) {{
+                                return;
+                            }}
+                            if ((now() - lastEmitAt) < getMoveEventThrottleMs()) {{
+                                schedule();
+                                return;
+                            }}
+                            flush();
+                        }});
+                    }};
+                    return {{
+                        push: function(payload) {{
+                            pending = pending !== null && merge ? merge(pending, payload) : payload;
+                            schedule();
+                        }},
+                        flush
+                    }};
+                }};
+
+                const mouseMoveEmitter = createThrottledEmitter(null);
+                const touchMoveEmitter = createThrottledEmitter(null);
+                const wheelEmitter = createThrottledEmitter(function(previous, next) {{
+                    next.delta_x += previous.delta_x;
+                    next.delta_y += previous.delta_y;
+                    return next;
+                }});
+
+                map.on('mousemove', function(e) {{
+                    if (eventEnabled('mousemove')) {{
+                        mouseMoveEmitter.push(mousePayload('mousemove', e));
+                    }}
+                }});
+
+                for (const type of ['mousedown', 'mouseup', 'mouseout']) {{
+                    map.on(type, function(e) {{
+                        // Deliver the last position before the discrete event.
+                        mouseMoveEmitter.flush();
+                        if (eventEnabled(type)) {{
+                            dioxus.send(JSON.stringify(mousePayload(type, e)));
+                        }}
+                    }});
+                }}
+
+                map.on('touchmove', function(e) {{
+                    if (eventEnabled('touchmove')) {{
+                        touchMoveEmitter.push(touchPayload('touchmove', e));
+                    }}
+                }});
+
+                for (const type of ['touchstart', 'touchend']) {{
+                    map.on(type, function(e) {{
+                        touchMoveEmitter.flush();
+                        if (eventEnabled(type)) {{
+                            dioxus.send(JSON.stringify(touchPayload(type, e)));
+                        }}
+                    }});
+                }}
+
+                map.on('wheel', function(e) {{
+                    if (eventEnabled('wheel')) {{
+                        wheelEmitter.push(wheelPayload(e));
+                    }}
+                }});
+
+                // Gesture events; programmatic camera moves report false.
+                const gesturePayload = function(type, e) {{
+                    const center = map.getCenter();
+                    return {{
+                        type,
+                        user_initiated: isUserInitiated(e),
+                        center: {{ lat: center.lat, lng: center.lng }},
+                        zoom: map.getZoom(),
+                        bearing: map.getBearing(),
+                        pitch: map.getPitch()
+                    }};
+                }};
+
+                const dragEmitter = createThrottledEmitter(null);
+
+                for (const type of [
+                    'movestart', 'zoomstart', 'rotatestart', 'pitchstart',
+                    'dragstart', 'dragend', 'boxzoomstart', 'boxzoomend'
+                ]) {{
+                    map.on(type, function(e) {{
+                        if (type === 'dragend') {{
+                            dragEmitter.flush();
+                        }}
+                        // `movestart` always crosses: it drives `MapState::is_moving`.
+                        if (type === 'movestart' || eventEnabled(type)) {{
+                            dioxus.send(JSON.stringify(gesturePayload(type, e)));
+                        }}
+                    }});
+                }}
+
+                map.on('drag', function(e) {{
+                    if (eventEnabled('drag')) {{
+                        dragEmitter.push(gesturePayload('drag', e));
+                    }}
+                }});
+
+                map.on('zoomend', function(e) {{
                     dioxus.send(JSON.stringify({{
                         type: 'zoom',
-                        zoom: map.getZoom()
+                        zoom: map.getZoom(),
+                        user_initiated: isUserInitiated(e)
                     }}));
                 }});
 
-                map.on('rotateend', function() {{
+                map.on('rotateend', function(e) {{
                     dioxus.send(JSON.stringify({{
                         type: 'rotate',
-                        bearing: map.getBearing()
+                        bearing: map.getBearing(),
+                        user_initiated: isUserInitiated(e)
                     }}));
                 }});
 
-                map.on('pitchend', function() {{
+                map.on('pitchend', function(e) {{
                     dioxus.send(JSON.stringify({{
                         type: 'pitch',
-                        pitch: map.getPitch()
+                        pitch: map.getPitch(),
+                        user_initiated: isUserInitiated(e)
+                    }}));
+                }});
+
+                // Lifecycle and data events.
+                map.on('idle', function() {{
+                    if (eventEnabled('idle')) {{
+                        dioxus.send(JSON.stringify({{ type: 'idle' }}));
+                    }}
+                }});
+
+                for (const type of ['data', 'sourcedata', 'styledata', 'dataloading']) {{
+                    map.on(type, function(e) {{
+                        if (!eventEnabled(type)) {{
+                            return;
+                        }}
+                        dioxus.send(JSON.stringify({{
+                            type,
+                            data_type: e.dataType,
+                            source_id: typeof e.sourceId === 'string' ? e.sourceId : null,
+                            source_data_type: typeof e.sourceDataType === 'string' ? e.sourceDataType : null,
+                            is_source_loaded: typeof e.isSourceLoaded === 'boolean' ? e.isSourceLoaded : null
+                        }}));
+                    }});
+                }}
+
+                map.on('resize', function() {{
+                    if (!eventEnabled('resize')) {{
+                        return;
+                    }}
+                    const canvasContainer = map.getCanvasContainer();
+                    dioxus.send(JSON.stringify({{
+                        type: 'resize',
+                        width: canvasContainer.clientWidth,
+                        height: canvasContainer.clientHeight
                     }}));
                 }});
 
+                map.on('webglcontextlost', function() {{
+                    if (eventEnabled('webglcontextlost')) {{
+                        dioxus.send(JSON.stringify({{ type: 'webglcontextlost' }}));
+                    }}
+                }});
+
+                map.on('styleimagemissing', function(e) {{
+                    if (eventEnabled('styleimagemissing')) {{
+                        dioxus.send(JSON.stringify({{ type: 'styleimagemissing', id: e.id }}));
+                    }}
+                }});
+
+                // Only report loading status transitions; data events fire per tile.
+                let lastLoadState = null;
+                const emitLoadState = function() {{
+                    const styleLoaded = !!map.isStyleLoaded();
+                    const tilesLoaded = !!map.areTilesLoaded();
+                    const key = styleLoaded + ':' + tilesLoaded;
+                    if (key === lastLoadState) {{
+                        return;
+                    }}
+                    lastLoadState = key;
+                    dioxus.send(JSON.stringify({{
+                        type: 'load_state',
+                        style_loaded: styleLoaded,
+                        tiles_loaded: tilesLoaded
+                    }}));
+                }};
+                map.on('styledata', emitLoadState);
+                map.on('dataloading', emitLoadState);
+                map.on('data', emitLoadState);
+                map.on('idle', emitLoadState);
+
                 map.on('load', function() {{
                     dioxus.send(JSON.stringify({{ type: 'ready' }}));
-                    emitMoveEvent('move_load');
+                    emitMoveEvent('move_load', false);
+                    emitLoadState();
                 }});
 
                 map.on('error', function(e) {{
@@ -363,14 +656,8 @@ pub fn destroy_map_js(map_id: &str) -> String {
             const mapHandlers = window.__dioxus_maplibre_layer_handlers && window.__dioxus_maplibre_layer_handlers[{map_id_lit}];
             if (map && mapHandlers) {{
                 for (const [layerId, handlers] of Object.entries(mapHandlers)) {{
-                    if (handlers && handlers.click) {{
-                        map.off('click', layerId, handlers.click);
-                    }}
-                    if (handlers && handlers.mouseenter) {{
-                        map.off('mouseenter', layerId, handlers.mouseenter);
-                    }}
-                    if (handlers && handlers.mouseleave) {{
-                        map.off('mouseleave', layerId, handlers.mouseleave);
+                    for (const [eventName, handler] of Object.entries(handlers || {{}})) {{
+                        map.off(eventName, layerId, handler);
                     }}
                 }}
                 delete window.__dioxus_maplibre_layer_handlers[{map_id_lit}];
@@ -1,29 +1,90 @@
 //! Marker management JS bridge.
 
-use super::find_map_js;
 use super::js_escape::js_single_quoted;
+use super::{fallible_map_js, find_map_js};
 
+/// Generate JS to add a marker
+///
+/// When `content_element_id` is set, that DOM element becomes the marker's
+/// element instead of the default pin or emoji.
 pub fn add_marker_js(
     map_id: &str,
     marker_id: &str,
     lat: f64,
     lng: f64,
     options_json: &str,
+    content_element_id: Option<&str>,
 ) -> String {
     let find = find_map_js(map_id);
-    let map_id_lit = js_single_quoted(map_id);
-    let marker_id_lit = js_single_quoted(marker_id);
+    let body = add_marker_body_js(
+        map_id,
+        marker_id,
+        lat,
+        lng,
+        options_json,
+        content_element_id,
+    );
     format!(
         r#"
         (function() {{
             {find}
+            {body}
+        }})();
+        "#
+    )
+}
+
+/// Generate fallible JS to add a marker
+pub fn try_add_marker_js(
+    map_id: &str,
+    marker_id: &str,
+    lat: f64,
+    lng: f64,
+    options_json: &str,
+    content_element_id: Option<&str>,
+) -> String {
+    let body = add_marker_body_js(
+        map_id,
+        marker_id,
+        lat,
+        lng,
+        options_json,
+        content_element_id,
+    );
+    fallible_map_js(map_id, &body)
+}
 
+/// Marker creation body shared by the plain and fallible bridges (expects `map`).
+fn add_marker_body_js(
+    map_id: &str,
+    marker_id: &str,
+    lat: f64,
+    lng: f64,
+    options_json: &str,
+    content_element_id: Option<&str>,
+) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let marker_id_lit = js_single_quoted(marker_id);
+    let content_element_id_lit =
+        content_element_id.map_or_else(|| "null".to_string(), js_single_quoted);
+    format!(
+        r#"
             const opts = {options_json};
+            const contentElementId = {content_element_id_lit};
 
             let markerOpts = {{}};
             let el = null;
+            let contentHost = null;
 
-            if (opts.emoji) {{
+            if (contentElementId) {{
+                el = document.getElementById(contentElementId);
+                if (!el) {{
+                    throw new Error('marker content element not found: ' + contentElementId);
+                }}
+                // Remember where the element lives so it can be handed back on removal.
+                contentHost = el.parentNode;
+                markerOpts.element = el;
+            }} else if (opts.emoji) {{
                 el = document.createElement('div');
                 el.className = 'maplibre-marker-emoji';
                 el.innerHTML = opts.emoji;
@@ -55,9 +116,23 @@ pub fn add_marker_js(
             }}
             window.__dioxus_maplibre_markers[{map_id_lit}][{marker_id_lit}] = marker;
 
+            // DOM listeners are tracked so a reused content element can be released cleanly.
+            marker.__dioxusContentHost = contentHost;
+            marker.__dioxusListeners = [];
+            const listen = function(type, handler) {{
+                marker.getElement().addEventListener(type, handler);
+                marker.__dioxusListeners.push([type, handler]);
+            }};
+
             // Click handler
-            marker.getElement().addEventListener('click', function(e) {{
-                e.stopPropagation();
+            listen('click', function(e) {{
+                if (contentHost) {{
+                    // Let the click bubble to Dioxus handlers inside the content,
+                    // but mark it so the map does not report it as a map click.
+                    e.__dioxusMarkerClick = true;
+                }} else {{
+                    e.stopPropagation();
+                }}
                 if (window.__dioxus_maplibre_sendEvent) {{
                     const lngLat = marker.getLngLat();
                     window.__dioxus_maplibre_sendEvent(JSON.stringify({{
@@ -68,6 +143,15 @@ pub fn add_marker_js(
                 }}
             }});
 
+            if (contentHost) {{
+                // Double-clicks and context menus on RSX content belong to the marker too.
+                const markMarkerEvent = function(e) {{
+                    e.__dioxusMarkerClick = true;
+                }};
+                listen('dblclick', markMarkerEvent);
+                listen('contextmenu', markMarkerEvent);
+            }}
+
             // Drag handlers (only if marker is draggable)
             if (opts.draggable) {{
                 marker.on('dragstart', function() {{
@@ -93,7 +177,7 @@ pub fn add_marker_js(
             }}
 
             // Hover handlers
-            marker.getElement().addEventListener('mouseenter', function(e) {{
+            listen('mouseenter', function(e) {{
                 if (window.__dioxus_maplibre_sendEvent) {{
                     const lngLat = marker.getLngLat();
                     window.__dioxus_maplibre_sendEvent(JSON.stringify({{
@@ -107,7 +191,7 @@ pub fn add_marker_js(
                 }}
             }});
 
-            marker.getElement().addEventListener('mouseleave', function(e) {{
+            listen('mouseleave', function(e) {{
                 if (window.__dioxus_maplibre_sendEvent) {{
                     const lngLat = marker.getLngLat();
                     window.__dioxus_maplibre_sendEvent(JSON.stringify({{
@@ -119,9 +203,26 @@ pub fn add_marker_js(
                         cursor_y: e.clientY
                     }}));
                 }}
-            }});
-        }})();
-        "#
+            }});"#
+    )
+}
+
+/// JS snippet removing `markers[marker_id]` from the map and registry.
+///
+/// A content element is detached from the marker and returned to its host node,
+/// so the Dioxus-owned DOM survives the marker being re-created.
+fn release_marker_js(marker_id_lit: &str) -> String {
+    format!(
+        r#"const marker = markers[{marker_id_lit}];
+                marker.remove();
+                const markerEl = marker.getElement();
+                for (const [type, handler] of marker.__dioxusListeners || []) {{
+                    markerEl.removeEventListener(type, handler);
+                }}
+                if (marker.__dioxusContentHost) {{
+                    marker.__dioxusContentHost.appendChild(markerEl);
+                }}
+                delete markers[{marker_id_lit}];"#
     )
 }
 
@@ -129,13 +230,13 @@ pub fn add_marker_js(
 pub fn remove_marker_js(map_id: &str, marker_id: &str) -> String {
     let map_id_lit = js_single_quoted(map_id);
     let marker_id_lit = js_single_quoted(marker_id);
+    let release = release_marker_js(&marker_id_lit);
     format!(
         r#"
         (function() {{
             const markers = window.__dioxus_maplibre_markers && window.__dioxus_maplibre_markers[{map_id_lit}];
             if (markers && markers[{marker_id_lit}]) {{
-                markers[{marker_id_lit}].remove();
-                delete markers[{marker_id_lit}];
+                {release}
             }}
         }})();
         "#
@@ -157,3 +258,49 @@ pub fn update_marker_position_js(map_id: &str, marker_id: &str, lat: f64, lng: f
         "#
     )
 }
+
+/// Generate fallible JS to remove a marker
+pub fn try_remove_marker_js(map_id: &str, marker_id: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let marker_id_lit = js_single_quoted(marker_id);
+    let release = release_marker_js(&marker_id_lit);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            const markers = window.__dioxus_maplibre_markers && window.__dioxus_maplibre_markers[{map_id_lit}];
+            if (!markers || !markers[{marker_id_lit}]) {{
+                fail('marker_missing', {{ id: {marker_id_lit} }});
+            }}
+            {release}
+            "#
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_marker_js, remove_marker_js};
+
+    #[test]
+    fn add_marker_js_uses_content_element() {
+        let js = add_marker_js("map1", "m1", 60.0, 24.0, "{}", Some("content'1"));
+        assert!(js.contains("const contentElementId = 'content\\'1';"));
+        assert!(js.contains("markerOpts.element = el;"));
+        assert!(js.contains("listen('dblclick', markMarkerEvent);"));
+        assert!(js.contains("listen('contextmenu', markMarkerEvent);"));
+    }
+
+    #[test]
+    fn add_marker_js_without_content_element() {
+        let js = add_marker_js("map1", "m1", 60.0, 24.0, "{}", None);
+        assert!(js.contains("const contentElementId = null;"));
+    }
+
+    #[test]
+    fn remove_marker_js_returns_content_to_host() {
+        let js = remove_marker_js("map1", "m1");
+        assert!(js.contains("marker.__dioxusContentHost.appendChild(markerEl);"));
+        assert!(js.contains("delete markers['m1'];"));
+    }
+}
@@ -3,6 +3,7 @@
 // On other targets they appear unused but we keep them available for tests.
 #![allow(dead_code, unused_imports)]
 
+mod batch;
 mod controls;
 mod core;
 mod feature_state;
@@ -20,9 +21,11 @@ mod sources;
 mod style;
 mod terrain_atmosphere;
 
-pub(crate) use core::find_map_js;
-pub use core::generate_map_id;
+pub(crate) use core::{fallible_map_js, find_map_js};
+pub use core::{generate_element_id, generate_map_id};
+pub(crate) use js_escape::base64_encode;
 
+pub use batch::*;
 pub use controls::*;
 pub use feature_state::*;
 pub use getters::*;
@@ -1,7 +1,12 @@
 //! Popup management JS bridge.
 
-use super::find_map_js;
 use super::js_escape::{js_single_quoted, js_template_literal};
+use super::{fallible_map_js, find_map_js};
+
+/// Generate JS to add a standalone popup
+///
+/// When `content_element_id` is set, that DOM element is moved into the popup
+/// (and returned to its parent when the popup closes) instead of using `html`.
 pub fn add_popup_js(
     map_id: &str,
     popup_id: &str,
@@ -9,15 +14,75 @@ pub fn add_popup_js(
     lng: f64,
     html: &str,
     options_json: &str,
+    content_element_id: Option<&str>,
 ) -> String {
     let find = find_map_js(map_id);
+    let body = add_popup_body_js(
+        map_id,
+        popup_id,
+        lat,
+        lng,
+        html,
+        options_json,
+        content_element_id,
+        "console.error('[dioxus-maplibre] popup content element not found:', contentElementId);\n                    return;",
+    );
+    format!(
+        r#"
+        (function() {{
+            {find}
+            {body}
+        }})();
+        "#
+    )
+}
+
+/// Generate fallible JS to add a standalone popup
+///
+/// A missing content element is reported as a JS exception instead of logged.
+pub fn try_add_popup_js(
+    map_id: &str,
+    popup_id: &str,
+    lat: f64,
+    lng: f64,
+    html: &str,
+    options_json: &str,
+    content_element_id: Option<&str>,
+) -> String {
+    let body = add_popup_body_js(
+        map_id,
+        popup_id,
+        lat,
+        lng,
+        html,
+        options_json,
+        content_element_id,
+        "throw new Error('popup content element not found: ' + contentElementId);",
+    );
+    fallible_map_js(map_id, &body)
+}
+
+/// Popup creation body shared by the plain and fallible bridges (expects `map`).
+///
+/// `on_missing_element` runs when the content element cannot be found.
+#[allow(clippy::too_many_arguments)]
+fn add_popup_body_js(
+    map_id: &str,
+    popup_id: &str,
+    lat: f64,
+    lng: f64,
+    html: &str,
+    options_json: &str,
+    content_element_id: Option<&str>,
+    on_missing_element: &str,
+) -> String {
     let map_id_lit = js_single_quoted(map_id);
     let popup_id_lit = js_single_quoted(popup_id);
     let escaped_html = js_template_literal(html);
+    let content_element_id_lit =
+        content_element_id.map_or_else(|| "null".to_string(), js_single_quoted);
     format!(
         r#"
-        (function() {{
-            {find}
             const opts = {options_json};
             const popupOpts = {{}};
             if (opts.offset) popupOpts.offset = opts.offset;
@@ -27,16 +92,31 @@ pub fn add_popup_js(
             if (opts.maxWidth) popupOpts.maxWidth = opts.maxWidth;
             if (opts.className) popupOpts.className = opts.className;
 
-            const popup = new maplibregl.Popup(popupOpts)
-                .setLngLat([{lng}, {lat}])
-                .setHTML(`{escaped_html}`)
-                .addTo(map);
+            const popup = new maplibregl.Popup(popupOpts).setLngLat([{lng}, {lat}]);
+
+            const contentElementId = {content_element_id_lit};
+            if (contentElementId) {{
+                const el = document.getElementById(contentElementId);
+                if (!el) {{
+                    {on_missing_element}
+                }}
+                // Hand the Dioxus-owned element back to its host whenever the popup closes.
+                const contentHost = el.parentNode;
+                popup.setDOMContent(el);
+                popup.on('close', function() {{
+                    if (el.parentNode !== contentHost) {{
+                        contentHost.appendChild(el);
+                    }}
+                }});
+            }} else {{
+                popup.setHTML(`{escaped_html}`);
+            }}
+            popup.addTo(map);
 
             // Store popup reference
             if (!window.__dioxus_maplibre_popups) window.__dioxus_maplibre_popups = {{}};
             if (!window.__dioxus_maplibre_popups[{map_id_lit}]) window.__dioxus_maplibre_popups[{map_id_lit}] = {{}};
             window.__dioxus_maplibre_popups[{map_id_lit}][{popup_id_lit}] = popup;
-        }})();
         "#
     )
 }
@@ -60,13 +140,37 @@ pub fn remove_popup_js(map_id: &str, popup_id: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::add_popup_js;
+    use super::{add_popup_js, try_add_popup_js};
 
     #[test]
     fn add_popup_js_escapes_html_and_ids() {
-        let js = add_popup_js("map'1", "popup'1", 60.0, 24.0, "<div>${`x`}</div>", "{}");
+        let js = add_popup_js(
+            "map'1",
+            "popup'1",
+            60.0,
+            24.0,
+            "<div>${`x`}</div>",
+            "{}",
+            None,
+        );
         assert!(js.contains("'map\\'1'"));
         assert!(js.contains("'popup\\'1'"));
         assert!(js.contains("<div>\\${\\`x\\`}</div>"));
+        assert!(js.contains("const contentElementId = null;"));
+    }
+
+    #[test]
+    fn add_popup_js_uses_content_element() {
+        let js = add_popup_js("map1", "popup1", 60.0, 24.0, "", "{}", Some("content_1"));
+        assert!(js.contains("const contentElementId = 'content_1';"));
+        assert!(js.contains("popup.setDOMContent(el);"));
+    }
+
+    #[test]
+    fn try_add_popup_js_fails_on_missing_content_element() {
+        let js = try_add_popup_js("map1", "popup1", 60.0, 24.0, "", "{}", Some("content_1"));
+        assert!(js.contains("throw new Error('popup content element not found: '"));
+        assert!(!js.contains("popup content element not found:', contentElementId"));
+        assert!(js.contains("popup.addTo(map);"));
     }
 }
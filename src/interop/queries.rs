@@ -25,11 +25,12 @@ pub fn query_rendered_features_js(map_id: &str, options_json: &str) -> String {
             }}
 
             return features.map(f => ({{
-                id: Number.isFinite(f.id) ? Math.trunc(f.id) : null,
+                id: typeof f.id === 'string' ? f.id : (Number.isFinite(f.id) ? Math.trunc(f.id) : null),
                 geometry: f.geometry,
                 properties: f.properties || {{}},
                 source: f.source,
-                sourceLayer: f.sourceLayer || null
+                sourceLayer: f.sourceLayer || null,
+                state: f.state || {{}}
             }}));
         }} catch (err) {{
             console.error('[dioxus-maplibre] Failed to query rendered features:', err);
@@ -59,11 +60,12 @@ pub fn query_rendered_features_at_js(map_id: &str, x: f64, y: f64, options_json:
             }}
 
             return features.map(f => ({{
-                id: Number.isFinite(f.id) ? Math.trunc(f.id) : null,
+                id: typeof f.id === 'string' ? f.id : (Number.isFinite(f.id) ? Math.trunc(f.id) : null),
                 geometry: f.geometry,
                 properties: f.properties || {{}},
                 source: f.source,
-                sourceLayer: f.sourceLayer || null
+                sourceLayer: f.sourceLayer || null,
+                state: f.state || {{}}
             }}));
         }} catch (err) {{
             console.error('[dioxus-maplibre] Failed to query rendered features at point:', err);
@@ -85,11 +87,12 @@ pub fn query_source_features_js(map_id: &str, source_id: &str, options_json: &st
             const features = map.querySourceFeatures({source_id_lit}, opts);
 
             return features.map(f => ({{
-                id: Number.isFinite(f.id) ? Math.trunc(f.id) : null,
+                id: typeof f.id === 'string' ? f.id : (Number.isFinite(f.id) ? Math.trunc(f.id) : null),
                 geometry: f.geometry,
                 properties: f.properties || {{}},
                 source: {source_id_lit},
-                sourceLayer: f.sourceLayer || null
+                sourceLayer: f.sourceLayer || null,
+                state: f.state || {{}}
             }}));
         }} catch (err) {{
             console.error('[dioxus-maplibre] Failed to query source features:', err);
@@ -1,7 +1,7 @@
 //! Source management JS bridge.
 
-use super::find_map_js;
 use super::js_escape::js_single_quoted;
+use super::{fallible_map_js, find_map_js};
 
 /// Generate JS to add a GeoJSON source
 pub fn add_geojson_source_js(map_id: &str, source_id: &str, options_json: &str) -> String {
@@ -110,3 +110,95 @@ pub fn remove_source_js(map_id: &str, source_id: &str) -> String {
         "#
     )
 }
+
+/// Generate fallible JS to add a source of the given type
+///
+/// Reports `source_exists` instead of silently keeping the existing source.
+pub fn try_add_source_js(
+    map_id: &str,
+    source_id: &str,
+    source_type: &str,
+    options_json: &str,
+) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let source_id_lit = js_single_quoted(source_id);
+    let source_type_lit = js_single_quoted(source_type);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            const opts = {options_json};
+            if (map.getSource({source_id_lit})) {{
+                fail('source_exists', {{ id: {source_id_lit} }});
+            }}
+            map.addSource({source_id_lit}, {{
+                type: {source_type_lit},
+                ...opts
+            }});
+            const sourceRegistry = window.__dioxus_maplibre_sources && window.__dioxus_maplibre_sources[{map_id_lit}];
+            if (sourceRegistry && map.getSource({source_id_lit})) {{
+                sourceRegistry[{source_id_lit}] = {{
+                    type: {source_type_lit},
+                    options: JSON.parse(JSON.stringify(opts))
+                }};
+            }}
+            "#
+        ),
+    )
+}
+
+/// Generate fallible JS to update a GeoJSON source's data
+pub fn try_update_geojson_source_js(map_id: &str, source_id: &str, data_json: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let source_id_lit = js_single_quoted(source_id);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            const source = map.getSource({source_id_lit});
+            if (!source) {{
+                fail('source_missing', {{ id: {source_id_lit} }});
+            }}
+            source.setData({data_json});
+            const sourceRegistry = window.__dioxus_maplibre_sources && window.__dioxus_maplibre_sources[{map_id_lit}];
+            if (sourceRegistry && sourceRegistry[{source_id_lit}]) {{
+                sourceRegistry[{source_id_lit}].options = sourceRegistry[{source_id_lit}].options || {{}};
+                sourceRegistry[{source_id_lit}].options.data = {data_json};
+            }}
+            "#
+        ),
+    )
+}
+
+/// Generate fallible JS to remove a source from the map
+pub fn try_remove_source_js(map_id: &str, source_id: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    let source_id_lit = js_single_quoted(source_id);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            if (!map.getSource({source_id_lit})) {{
+                fail('source_missing', {{ id: {source_id_lit} }});
+            }}
+            map.removeSource({source_id_lit});
+            const sourceRegistry = window.__dioxus_maplibre_sources && window.__dioxus_maplibre_sources[{map_id_lit}];
+            if (sourceRegistry && !map.getSource({source_id_lit})) {{
+                delete sourceRegistry[{source_id_lit}];
+            }}
+            "#
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::try_add_source_js;
+
+    #[test]
+    fn try_add_source_js_reports_existing_source() {
+        let js = try_add_source_js("map1", "src'1", "geojson", "{}");
+        assert!(js.contains("fail('source_exists', { id: 'src\\'1' });"));
+        assert!(js.contains("type: 'geojson'"));
+    }
+}
@@ -1,7 +1,10 @@
 //! Style and runtime style replay JS bridge.
 
-use super::find_map_js;
+use super::images::STYLE_IMAGE_HELPERS_JS;
 use super::js_escape::js_single_quoted;
+use super::layers::LAYER_ANCHOR_HELPERS_JS;
+use super::{fallible_map_js, find_map_js};
+use crate::options::MapStyle;
 
 pub fn set_move_event_throttle_js(map_id: &str, throttle_ms: u32) -> String {
     let find = find_map_js(map_id);
@@ -16,11 +19,45 @@ pub fn set_move_event_throttle_js(map_id: &str, throttle_ms: u32) -> String {
     )
 }
 
+/// Generate JS to replace the set of opt-in event types forwarded to Rust
+pub fn set_opt_in_events_js(map_id: &str, opt_in_events: &[&str]) -> String {
+    let find = find_map_js(map_id);
+    let opt_in_events_json =
+        serde_json::to_string(opt_in_events).unwrap_or_else(|_| "[]".to_string());
+    format!(
+        r#"
+        (function() {{
+            {find}
+            map.__dioxusOptInEvents = new Set({opt_in_events_json});
+        }})();
+        "#
+    )
+}
+
+/// Render a style as a JS expression: a quoted URL or the inline style object
+pub(crate) fn style_value_js(style: &MapStyle) -> String {
+    match style {
+        MapStyle::Url(url) => js_single_quoted(url),
+        MapStyle::Spec(spec) => serde_json::to_string(spec).unwrap_or_else(|_| "{}".to_string()),
+    }
+}
+
+/// Generate JS returning the current style document
+pub fn get_style_js(map_id: &str) -> String {
+    let find = find_map_js(map_id);
+    format!(
+        r#"
+        {find}
+        return map.getStyle();
+        "#
+    )
+}
+
 /// Generate JS to set the map style
-pub fn set_style_js(map_id: &str, style_url: &str) -> String {
+pub fn set_style_js(map_id: &str, style: &MapStyle) -> String {
     let find = find_map_js(map_id);
     let map_id_lit = js_single_quoted(map_id);
-    let style_url_lit = js_single_quoted(style_url);
+    let style_value = style_value_js(style);
     format!(
         r#"
         (function() {{
@@ -32,6 +69,8 @@ pub fn set_style_js(map_id: &str, style_url: &str) -> String {
             const terrainState = window.__dioxus_maplibre_terrain && window.__dioxus_maplibre_terrain[{map_id_lit}];
             const skyState = window.__dioxus_maplibre_sky && window.__dioxus_maplibre_sky[{map_id_lit}];
             const fogState = window.__dioxus_maplibre_fog && window.__dioxus_maplibre_fog[{map_id_lit}];
+            {STYLE_IMAGE_HELPERS_JS}
+            {LAYER_ANCHOR_HELPERS_JS}
 
             if (!window.__dioxus_maplibre_style_switch_tokens) {{
                 window.__dioxus_maplibre_style_switch_tokens = {{}};
@@ -90,8 +129,7 @@ pub fn set_style_js(map_id: &str, style_url: &str) -> String {
                         if (!layerDef) continue;
                         try {{
                             if (map.getLayer(layerId)) continue;
-                            const layerToAdd = JSON.parse(JSON.stringify(layerDef));
-                            map.addLayer(layerToAdd);
+                            addAnchoredLayer(map, layerDef, layerRegistry);
                         }} catch (err) {{
                             console.error('[dioxus-maplibre] Failed replaying layer:', layerId, err);
                         }}
@@ -122,14 +160,13 @@ pub fn set_style_js(map_id: &str, style_url: &str) -> String {
                     }}
 
                     if (imageRegistry) {{
-                        for (const [imageId, url] of Object.entries(imageRegistry)) {{
-                            if (!url) continue;
-                            map.loadImage(url).then((response) => {{
-                                if (!response || !response.data) return;
-                                if (!map.hasImage(imageId)) {{
-                                    map.addImage(imageId, response.data);
-                                }}
-                            }}).catch((err) => {{
+                        for (const [imageId, entry] of Object.entries(imageRegistry)) {{
+                            if (!entry) continue;
+                            // `load_image` stores a URL, `add_image` a full spec
+                            const spec = typeof entry === 'string'
+                                ? {{ source: {{ kind: 'url', url: entry }} }}
+                                : entry;
+                            addStyleImage(map, imageId, spec, () => imageRegistry[imageId] === entry).catch((err) => {{
                                 console.error('[dioxus-maplibre] Failed replaying image:', imageId, err);
                             }});
                         }}
@@ -174,7 +211,7 @@ pub fn set_style_js(map_id: &str, style_url: &str) -> String {
             map.on('style.load', onStyleLoad);
             map.on('styledata', onStyleData);
             awaitingNewStyle = true;
-            map.setStyle({style_url_lit});
+            map.setStyle({style_value});
 
             setTimeout(function() {{
                 if (!awaitingNewStyle || replayed) {{
@@ -198,3 +235,200 @@ pub fn set_style_js(map_id: &str, style_url: &str) -> String {
         "#
     )
 }
+
+/// Generate JS to switch styles through MapLibre's style diff
+///
+/// Tracked runtime sources and layers (preferring their live definitions in
+/// the previous style), terrain and sky are merged into the next style so the
+/// diff leaves them untouched. Images and fog live outside the style document
+/// and are restored once the new style has loaded, if they went missing.
+pub fn set_style_diff_js(map_id: &str, style: &MapStyle, transform_style: Option<&str>) -> String {
+    let find = find_map_js(map_id);
+    let map_id_lit = js_single_quoted(map_id);
+    let style_value = style_value_js(style);
+    let user_transform = transform_style.map_or_else(
+        || "null".to_string(),
+        |body| format!("function(previous, next) {{ {body} }}"),
+    );
+    format!(
+        r#"
+        (function() {{
+            {find}
+            const sourceRegistry = window.__dioxus_maplibre_sources && window.__dioxus_maplibre_sources[{map_id_lit}];
+            const layerRegistry = window.__dioxus_maplibre_layers && window.__dioxus_maplibre_layers[{map_id_lit}];
+            const layerOrder = window.__dioxus_maplibre_layer_order && window.__dioxus_maplibre_layer_order[{map_id_lit}];
+            const imageRegistry = window.__dioxus_maplibre_images && window.__dioxus_maplibre_images[{map_id_lit}];
+            const terrainState = window.__dioxus_maplibre_terrain && window.__dioxus_maplibre_terrain[{map_id_lit}];
+            const skyState = window.__dioxus_maplibre_sky && window.__dioxus_maplibre_sky[{map_id_lit}];
+            const fogState = window.__dioxus_maplibre_fog && window.__dioxus_maplibre_fog[{map_id_lit}];
+            {STYLE_IMAGE_HELPERS_JS}
+            {LAYER_ANCHOR_HELPERS_JS}
+
+            // Supersedes any full-replay switch still waiting for its style
+            if (!window.__dioxus_maplibre_style_switch_tokens) {{
+                window.__dioxus_maplibre_style_switch_tokens = {{}};
+            }}
+            const styleSwitchToken = `${{Date.now()}}_${{Math.random().toString(36).slice(2)}}`;
+            window.__dioxus_maplibre_style_switch_tokens[{map_id_lit}] = styleSwitchToken;
+            const isActive = () => window.__dioxus_maplibre_style_switch_tokens[{map_id_lit}] === styleSwitchToken;
+
+            const userTransform = {user_transform};
+            const copy = (value) => JSON.parse(JSON.stringify(value));
+
+            const transformStyle = function(previous, next) {{
+                const previousSources = (previous && previous.sources) || {{}};
+                const previousLayers = new Map(((previous && previous.layers) || []).map((layer) => [layer.id, layer]));
+                const style = {{
+                    ...next,
+                    sources: {{ ...(next.sources || {{}}) }},
+                    layers: (next.layers || []).slice()
+                }};
+
+                for (const [sourceId, sourceDef] of Object.entries(sourceRegistry || {{}})) {{
+                    if (!sourceDef || !sourceDef.type || style.sources[sourceId]) continue;
+                    style.sources[sourceId] = previousSources[sourceId]
+                        ? copy(previousSources[sourceId])
+                        : {{ type: sourceDef.type, ...copy(sourceDef.options || {{}}) }};
+                }}
+
+                const nextLayerIds = new Set(style.layers.map((layer) => layer.id));
+                const isManaged = (id) => !!layerRegistry[id];
+                const orderedLayerIds =
+                    layerOrder && layerOrder.length > 0
+                        ? layerOrder.slice()
+                        : Object.keys(layerRegistry || {{}});
+                for (const layerId of orderedLayerIds) {{
+                    const layerDef = layerRegistry && layerRegistry[layerId];
+                    if (!layerDef || nextLayerIds.has(layerId)) continue;
+                    const layerToAdd = previousLayers.has(layerId)
+                        ? copy(previousLayers.get(layerId))
+                        : layerSpec(layerDef);
+                    const anchor = resolveLayerAnchor(style.layers, layerDef, isManaged);
+                    const index = anchor === undefined
+                        ? -1
+                        : style.layers.findIndex((layer) => layer.id === anchor);
+                    if (index >= 0) {{
+                        style.layers.splice(index, 0, layerToAdd);
+                    }} else {{
+                        style.layers.push(layerToAdd);
+                    }}
+                }}
+
+                if (terrainState && terrainState.hasValue) {{
+                    style.terrain = copy(terrainState.value);
+                }}
+                if (skyState && skyState.hasValue) {{
+                    style.sky = copy(skyState.value);
+                }}
+
+                if (userTransform) {{
+                    try {{
+                        const transformed = userTransform(previous, style);
+                        if (transformed) {{
+                            return transformed;
+                        }}
+                    }} catch (err) {{
+                        console.error('[dioxus-maplibre] transformStyle hook failed:', err);
+                    }}
+                }}
+                return style;
+            }};
+
+            const restoreOutsideStyle = function() {{
+                if (!isActive()) {{
+                    map.off('styledata', restoreOutsideStyle);
+                    return;
+                }}
+                if (!map.isStyleLoaded()) {{
+                    return;
+                }}
+                map.off('styledata', restoreOutsideStyle);
+                if (fogState && fogState.hasValue && typeof map.setFog === 'function') {{
+                    try {{
+                        map.setFog(fogState.value);
+                    }} catch (err) {{
+                        console.error('[dioxus-maplibre] Failed restoring fog state:', err);
+                    }}
+                }}
+                for (const [imageId, entry] of Object.entries(imageRegistry || {{}})) {{
+                    if (!entry || map.hasImage(imageId)) continue;
+                    const spec = typeof entry === 'string'
+                        ? {{ source: {{ kind: 'url', url: entry }} }}
+                        : entry;
+                    addStyleImage(map, imageId, spec, () => imageRegistry[imageId] === entry).catch((err) => {{
+                        console.error('[dioxus-maplibre] Failed restoring image:', imageId, err);
+                    }});
+                }}
+            }};
+
+            map.on('styledata', restoreOutsideStyle);
+            setTimeout(function() {{
+                map.off('styledata', restoreOutsideStyle);
+            }}, 6000);
+            map.setStyle({style_value}, {{ diff: true, transformStyle }});
+        }})();
+        "#
+    )
+}
+
+/// Generate fallible JS to switch styles, with or without the style diff
+///
+/// Reports the validation errors MapLibre emits while the switch starts, which
+/// covers inline styles; a URL style is fetched later and its errors are not
+/// reported here.
+pub fn try_set_style_js(
+    map_id: &str,
+    style: &MapStyle,
+    diff: bool,
+    transform_style: Option<&str>,
+) -> String {
+    let switch = if diff {
+        set_style_diff_js(map_id, style, transform_style)
+    } else {
+        set_style_js(map_id, style)
+    };
+    fallible_map_js(map_id, &switch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{set_style_diff_js, set_style_js, try_set_style_js};
+    use crate::options::{MapStyle, StyleSpecification};
+
+    #[test]
+    fn set_style_js_inlines_spec_or_quotes_url() {
+        let js = set_style_js("map1", &MapStyle::from("https://example.com/style.json"));
+        assert!(js.contains("map.setStyle('https://example.com/style.json');"));
+
+        let js = set_style_js("map1", &StyleSpecification::default().into());
+        assert!(js.contains(r#"map.setStyle({"version":8,"sources":{},"layers":[]});"#));
+    }
+
+    #[test]
+    fn set_style_diff_js_merges_runtime_state_and_user_hook() {
+        let js = set_style_diff_js(
+            "map1",
+            &MapStyle::from("https://example.com/style.json"),
+            Some("return next;"),
+        );
+        assert!(js.contains(
+            "map.setStyle('https://example.com/style.json', { diff: true, transformStyle });"
+        ));
+        assert!(js.contains("const userTransform = function(previous, next) { return next; };"));
+        assert!(js.contains("resolveLayerAnchor(style.layers, layerDef, isManaged)"));
+
+        let js = set_style_diff_js("map1", &MapStyle::default(), None);
+        assert!(js.contains("const userTransform = null;"));
+    }
+
+    #[test]
+    fn try_set_style_js_runs_the_switch_inside_the_fallible_body() {
+        let style = MapStyle::from(StyleSpecification::default());
+        let js = try_set_style_js("map1", &style, false, None);
+        assert!(js.contains("invalid_style_spec"));
+        assert!(js.contains(r#"map.setStyle({"version":8,"sources":{},"layers":[]});"#));
+
+        let js = try_set_style_js("map1", &style, true, None);
+        assert!(js.contains("{ diff: true"));
+    }
+}
@@ -1,7 +1,7 @@
 //! Terrain, sky, and fog JS bridge.
 
-use super::find_map_js;
 use super::js_escape::js_single_quoted;
+use super::{fallible_map_js, find_map_js};
 
 pub fn set_terrain_js(map_id: &str, options_json: &str) -> String {
     let find = find_map_js(map_id);
@@ -21,6 +21,28 @@ pub fn set_terrain_js(map_id: &str, options_json: &str) -> String {
     )
 }
 
+/// Generate fallible JS to set terrain, checking the DEM source exists
+pub fn try_set_terrain_js(map_id: &str, options_json: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            const terrain = {options_json};
+            if (!map.getSource(terrain.source)) {{
+                fail('source_missing', {{ id: terrain.source }});
+            }}
+            map.setTerrain(terrain);
+            const terrainState = window.__dioxus_maplibre_terrain && window.__dioxus_maplibre_terrain[{map_id_lit}];
+            if (terrainState && validationErrors.length === 0) {{
+                terrainState.hasValue = true;
+                terrainState.value = JSON.parse(JSON.stringify(terrain));
+            }}
+            "#
+        ),
+    )
+}
+
 /// Generate JS to remove terrain
 pub fn remove_terrain_js(map_id: &str) -> String {
     let find = find_map_js(map_id);
@@ -59,6 +81,25 @@ pub fn set_sky_js(map_id: &str, options_json: &str) -> String {
     )
 }
 
+/// Generate fallible JS to set sky, reporting MapLibre validation errors
+pub fn try_set_sky_js(map_id: &str, options_json: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            const options = {options_json};
+            map.setSky(options);
+            const skyState = window.__dioxus_maplibre_sky && window.__dioxus_maplibre_sky[{map_id_lit}];
+            if (skyState && validationErrors.length === 0) {{
+                skyState.hasValue = true;
+                skyState.value = JSON.parse(JSON.stringify(options));
+            }}
+            "#
+        ),
+    )
+}
+
 /// Generate JS to remove sky
 pub fn remove_sky_js(map_id: &str) -> String {
     let find = find_map_js(map_id);
@@ -101,6 +142,25 @@ pub fn set_fog_js(map_id: &str, options_json: &str) -> String {
     )
 }
 
+/// Generate fallible JS to set fog, reporting MapLibre validation errors
+pub fn try_set_fog_js(map_id: &str, options_json: &str) -> String {
+    let map_id_lit = js_single_quoted(map_id);
+    fallible_map_js(
+        map_id,
+        &format!(
+            r#"
+            const options = {options_json};
+            map.setFog(options);
+            const fogState = window.__dioxus_maplibre_fog && window.__dioxus_maplibre_fog[{map_id_lit}];
+            if (fogState && validationErrors.length === 0) {{
+                fogState.hasValue = true;
+                fogState.value = JSON.parse(JSON.stringify(options));
+            }}
+            "#
+        ),
+    )
+}
+
 /// Generate JS to remove fog/atmosphere
 pub fn remove_fog_js(map_id: &str) -> String {
     let find = find_map_js(map_id);
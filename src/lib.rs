@@ -27,7 +27,9 @@
 //! }
 //! ```
 
+mod error;
 mod events;
+mod expr;
 mod handle;
 mod interop;
 mod options;
@@ -36,27 +38,51 @@ mod types;
 /// Map components
 pub mod components;
 
+/// Recording map backend for native tests
+#[cfg(feature = "testing")]
+pub mod testing;
+
 // Re-export public API — Types
-pub use types::{Bounds, LatLng, MapPosition, Point, QueryFeature};
+pub use types::{
+    Bounds, Feature, FeatureCollection, FeatureId, Geometry, LatLng, MapPosition, MapState, Point,
+    Position, QueryFeature,
+};
+
+// Re-export public API — Errors
+pub use error::{MapError, MapResult};
+
+// Re-export public API — Expressions
+pub use expr::{Expr, Interpolation};
 
 // Re-export public API — Events
 pub use events::{
-    LayerClickEvent, LayerHoverEvent, MapClickEvent, MapContextMenuEvent, MapDblClickEvent,
-    MapErrorEvent, MapEvent, MapMoveEvent, MapPitchEvent, MapReadyEvent, MapRotateEvent,
-    MapZoomEvent, MarkerClickEvent, MarkerDragEndEvent, MarkerDragStartEvent, MarkerHoverEvent,
+    LayerClickEvent, LayerHoverEvent, LayerHoverPhase, MapClickEvent, MapContextMenuEvent,
+    MapDataEvent, MapDataType, MapDblClickEvent, MapErrorEvent, MapEvent, MapGestureEvent,
+    MapLoadStateEvent, MapMouseEvent, MapMoveEvent, MapPitchEvent, MapReadyEvent, MapResizeEvent,
+    MapRotateEvent, MapStyleImageMissingEvent, MapTouchEvent, MapWheelEvent, MapZoomEvent,
+    MarkerClickEvent, MarkerDragEndEvent, MarkerDragStartEvent, MarkerHoverEvent, ModifierKeys,
+    MovePhase,
 };
 
 // Re-export public API — Options
 pub use options::{
     ControlPosition, EaseToOptions, FeatureIdentifier, FitBoundsOptions, FlyToOptions, FogOptions,
-    GeoJsonSourceOptions, ImageSourceOptions, JumpToOptions, LayerOptions, MarkerOptions, Padding,
-    PopupOptions, QueryOptions, RasterDemSourceOptions, RasterSourceOptions, SkyOptions,
-    TerrainOptions, VectorSourceOptions,
+    GeoJsonSourceOptions, ImageSourceOptions, JumpToOptions, LayerHoverOptions, LayerOptions,
+    LayerSlot, MapStyle, MarkerOptions, Padding, PopupOptions, QueryOptions,
+    RasterDemSourceOptions, RasterSourceOptions, SetStyleOptions, SkyOptions, StyleImageOptions,
+    StyleImageSource, StyleSpecification, StyleTransition, TerrainOptions, VectorSourceOptions,
+};
+
+// Re-export public API — Layer properties
+pub use options::{
+    Alignment, Anchor, BackgroundPaint, CirclePaint, CirclePitch, FillExtrusionPaint, FillPaint,
+    HeatmapPaint, LayerType, LineCap, LineJoin, LineLayout, LinePaint, PropertyValue, RasterPaint,
+    RasterResampling, SymbolLayout, SymbolPaint, SymbolPlacement, TextTransform, Visibility,
 };
 
 // Re-export public API — Handle & Component
 pub use components::{
-    Map, MapControl, MapControlKind, MapLayer, MapMarker, MapPopup, MapSource, MapSourceKind,
-    use_map_handle,
+    Map, MapControl, MapControlKind, MapFeatureState, MapFog, MapImage, MapLayer, MapMarker,
+    MapPopup, MapSky, MapSource, MapSourceKind, MapTerrain, use_map_handle, use_map_state,
 };
 pub use handle::MapHandle;
@@ -0,0 +1,98 @@
+//! Style image option models.
+
+use dioxus::prelude::Asset;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{MapError, MapResult};
+
+/// Where a style image's pixels come from
+#[derive(Debug, Clone, PartialEq)]
+pub enum StyleImageSource {
+    /// An image URL (PNG, JPEG, WebP, ...)
+    Url(String),
+    /// Raw RGBA pixels, `width * height * 4` bytes
+    Rgba {
+        width: u32,
+        height: u32,
+        data: Vec<u8>,
+    },
+    /// SVG markup, rasterized at `StyleImageOptions::pixel_ratio`
+    Svg(String),
+    /// A bundled Dioxus asset, e.g. `asset!("/assets/pin.png")`
+    Asset(Asset),
+}
+
+impl StyleImageSource {
+    /// Check that RGBA pixel data is exactly `width * height * 4` bytes
+    pub(crate) fn validate(&self) -> MapResult<()> {
+        if let Self::Rgba {
+            width,
+            height,
+            data,
+        } = self
+        {
+            let expected = u64::from(*width) * u64::from(*height) * 4;
+            if data.len() as u64 != expected {
+                return Err(MapError::InvalidStyleSpec {
+                    message: format!(
+                        "RGBA image is {width}x{height} and needs {expected} bytes, got {}",
+                        data.len()
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The bridge representation consumed by the image loader JS
+    ///
+    /// RGBA pixels travel base64-encoded rather than as a JSON number array.
+    pub(crate) fn to_js_spec(&self) -> serde_json::Value {
+        match self {
+            Self::Url(url) => serde_json::json!({ "kind": "url", "url": url }),
+            Self::Rgba {
+                width,
+                height,
+                data,
+            } => serde_json::json!({
+                "kind": "rgba",
+                "width": width,
+                "height": height,
+                "data": crate::interop::base64_encode(data),
+            }),
+            Self::Svg(svg) => serde_json::json!({ "kind": "svg", "svg": svg }),
+            Self::Asset(asset) => serde_json::json!({ "kind": "url", "url": asset.to_string() }),
+        }
+    }
+}
+
+impl From<Asset> for StyleImageSource {
+    fn from(value: Asset) -> Self {
+        Self::Asset(value)
+    }
+}
+
+/// Options passed to MapLibre's `addImage`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StyleImageOptions {
+    /// Ratio of image pixels to screen pixels (default 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pixel_ratio: Option<f64>,
+
+    /// Treat the image as a signed distance field, recolorable with `icon-color`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sdf: Option<bool>,
+
+    /// Horizontal ranges (in image pixels) that stretch for `icon-text-fit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stretch_x: Option<Vec<[f64; 2]>>,
+
+    /// Vertical ranges (in image pixels) that stretch for `icon-text-fit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stretch_y: Option<Vec<[f64; 2]>>,
+
+    /// Area `[x1, y1, x2, y2]` that text fits into for `icon-text-fit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<[f64; 4]>,
+}
@@ -0,0 +1,622 @@
+//! Typed paint and layout properties per layer type.
+//!
+//! Each property accepts a literal or an `Expr`. Unset properties are omitted,
+//! so MapLibre's defaults apply.
+
+use serde::{Deserialize, Serialize};
+
+use crate::expr::Expr;
+
+/// A layer property value: a literal or a data-driven expression
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PropertyValue<T> {
+    Literal(T),
+    Expr(Expr),
+}
+
+impl<T> From<Expr> for PropertyValue<T> {
+    fn from(value: Expr) -> Self {
+        Self::Expr(value)
+    }
+}
+
+macro_rules! impl_from_literal {
+    ($($target:ty => $($source:ty),+;)*) => {
+        $($(
+            impl From<$source> for PropertyValue<$target> {
+                fn from(value: $source) -> Self {
+                    Self::Literal(value.into())
+                }
+            }
+        )+)*
+    };
+}
+
+impl_from_literal! {
+    f64 => f64, f32, i32, u32;
+    bool => bool;
+    String => String, &str;
+    [f64; 2] => [f64; 2];
+    Vec<f64> => Vec<f64>;
+    Vec<String> => Vec<String>;
+    Visibility => Visibility;
+    LineCap => LineCap;
+    LineJoin => LineJoin;
+    Alignment => Alignment;
+    CirclePitch => CirclePitch;
+    SymbolPlacement => SymbolPlacement;
+    Anchor => Anchor;
+    TextTransform => TextTransform;
+    RasterResampling => RasterResampling;
+}
+
+/// Layer type (the `type` field of a MapLibre layer)
+///
+/// Unknown types are kept as `Other` so new MapLibre layer types pass through.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LayerType {
+    Background,
+    Fill,
+    Line,
+    Symbol,
+    Circle,
+    Heatmap,
+    FillExtrusion,
+    Raster,
+    Hillshade,
+    Other(String),
+}
+
+impl LayerType {
+    /// The MapLibre style spec name of this layer type
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Background => "background",
+            Self::Fill => "fill",
+            Self::Line => "line",
+            Self::Symbol => "symbol",
+            Self::Circle => "circle",
+            Self::Heatmap => "heatmap",
+            Self::FillExtrusion => "fill-extrusion",
+            Self::Raster => "raster",
+            Self::Hillshade => "hillshade",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<&str> for LayerType {
+    fn from(value: &str) -> Self {
+        match value {
+            "background" => Self::Background,
+            "fill" => Self::Fill,
+            "line" => Self::Line,
+            "symbol" => Self::Symbol,
+            "circle" => Self::Circle,
+            "heatmap" => Self::Heatmap,
+            "fill-extrusion" => Self::FillExtrusion,
+            "raster" => Self::Raster,
+            "hillshade" => Self::Hillshade,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for LayerType {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for LayerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for LayerType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for LayerType {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Serialize for LayerType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LayerType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Whether a layer is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Visibility {
+    Visible,
+    None,
+}
+
+/// Line end style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+/// Line corner style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LineJoin {
+    Bevel,
+    Round,
+    Miter,
+}
+
+/// Alignment of a symbol or circle relative to the map or viewport
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Alignment {
+    Map,
+    Viewport,
+    Auto,
+}
+
+/// Reference frame for `circle-pitch-scale` and `circle-pitch-alignment`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CirclePitch {
+    Map,
+    Viewport,
+}
+
+/// Symbol placement relative to its geometry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SymbolPlacement {
+    Point,
+    Line,
+    LineCenter,
+}
+
+/// Anchor position of an icon or label
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Anchor {
+    Center,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Case transformation for label text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TextTransform {
+    None,
+    Uppercase,
+    Lowercase,
+}
+
+/// Resampling used when a raster is overscaled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RasterResampling {
+    Linear,
+    Nearest,
+}
+
+/// Paint properties for `background` layers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct BackgroundPaint {
+    /// Fill color of the background
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<PropertyValue<String>>,
+
+    /// Image name used as a repeating pattern
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_pattern: Option<PropertyValue<String>>,
+
+    /// Opacity (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_opacity: Option<PropertyValue<f64>>,
+}
+
+/// Paint properties for `fill` layers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct FillPaint {
+    /// Whether the fill is antialiased
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_antialias: Option<PropertyValue<bool>>,
+
+    /// Opacity (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_opacity: Option<PropertyValue<f64>>,
+
+    /// Fill color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_color: Option<PropertyValue<String>>,
+
+    /// Outline color (requires `fill_antialias`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_outline_color: Option<PropertyValue<String>>,
+
+    /// Pixel offset `[x, y]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_translate: Option<PropertyValue<[f64; 2]>>,
+
+    /// Image name used as a repeating pattern
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_pattern: Option<PropertyValue<String>>,
+}
+
+/// Paint properties for `line` layers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct LinePaint {
+    /// Opacity (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_opacity: Option<PropertyValue<f64>>,
+
+    /// Line color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_color: Option<PropertyValue<String>>,
+
+    /// Line width in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_width: Option<PropertyValue<f64>>,
+
+    /// Width of an inner gap, drawing a casing around the line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_gap_width: Option<PropertyValue<f64>>,
+
+    /// Perpendicular offset in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_offset: Option<PropertyValue<f64>>,
+
+    /// Blur in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_blur: Option<PropertyValue<f64>>,
+
+    /// Dash pattern as multiples of the line width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_dasharray: Option<PropertyValue<Vec<f64>>>,
+
+    /// Pixel offset `[x, y]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_translate: Option<PropertyValue<[f64; 2]>>,
+
+    /// Image name used as a repeating pattern
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_pattern: Option<PropertyValue<String>>,
+
+    /// Color gradient along the line (requires `lineMetrics` on the source)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_gradient: Option<PropertyValue<String>>,
+}
+
+/// Layout properties for `line` layers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct LineLayout {
+    /// Line end style
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_cap: Option<PropertyValue<LineCap>>,
+
+    /// Line corner style
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_join: Option<PropertyValue<LineJoin>>,
+
+    /// Miter limit for sharp angles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_miter_limit: Option<PropertyValue<f64>>,
+
+    /// Round limit for shallow angles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_round_limit: Option<PropertyValue<f64>>,
+
+    /// Sort key; higher values draw on top
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_sort_key: Option<PropertyValue<f64>>,
+
+    /// Whether the layer is shown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<PropertyValue<Visibility>>,
+}
+
+/// Paint properties for `circle` layers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct CirclePaint {
+    /// Radius in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circle_radius: Option<PropertyValue<f64>>,
+
+    /// Fill color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circle_color: Option<PropertyValue<String>>,
+
+    /// Blur amount (1 blurs to the center)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circle_blur: Option<PropertyValue<f64>>,
+
+    /// Opacity (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circle_opacity: Option<PropertyValue<f64>>,
+
+    /// Pixel offset `[x, y]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circle_translate: Option<PropertyValue<[f64; 2]>>,
+
+    /// Scaling behavior when the map is pitched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circle_pitch_scale: Option<PropertyValue<CirclePitch>>,
+
+    /// Orientation when the map is pitched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circle_pitch_alignment: Option<PropertyValue<CirclePitch>>,
+
+    /// Stroke width in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circle_stroke_width: Option<PropertyValue<f64>>,
+
+    /// Stroke color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circle_stroke_color: Option<PropertyValue<String>>,
+
+    /// Stroke opacity (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circle_stroke_opacity: Option<PropertyValue<f64>>,
+}
+
+/// Layout properties for `symbol` layers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct SymbolLayout {
+    /// Placement relative to the geometry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_placement: Option<PropertyValue<SymbolPlacement>>,
+
+    /// Distance between symbols along a line, in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_spacing: Option<PropertyValue<f64>>,
+
+    /// Sort key; lower values are placed first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol_sort_key: Option<PropertyValue<f64>>,
+
+    /// Sprite image name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_image: Option<PropertyValue<String>>,
+
+    /// Icon scale factor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_size: Option<PropertyValue<f64>>,
+
+    /// Icon rotation in degrees
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_rotate: Option<PropertyValue<f64>>,
+
+    /// Part of the icon placed closest to the anchor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_anchor: Option<PropertyValue<Anchor>>,
+
+    /// Icon offset `[x, y]` in icon-size units
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_offset: Option<PropertyValue<[f64; 2]>>,
+
+    /// Show the icon even if it collides with other symbols
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_allow_overlap: Option<PropertyValue<bool>>,
+
+    /// Let other symbols overlap the icon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_ignore_placement: Option<PropertyValue<bool>>,
+
+    /// Orientation of the icon when rotating the map
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_rotation_alignment: Option<PropertyValue<Alignment>>,
+
+    /// Label text (usually an expression such as `Expr::get("name")`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_field: Option<PropertyValue<String>>,
+
+    /// Font stack
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_font: Option<PropertyValue<Vec<String>>>,
+
+    /// Font size in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_size: Option<PropertyValue<f64>>,
+
+    /// Wrap width in ems
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_max_width: Option<PropertyValue<f64>>,
+
+    /// Letter spacing in ems
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_letter_spacing: Option<PropertyValue<f64>>,
+
+    /// Part of the text placed closest to the anchor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_anchor: Option<PropertyValue<Anchor>>,
+
+    /// Text offset `[x, y]` in ems
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_offset: Option<PropertyValue<[f64; 2]>>,
+
+    /// Case transformation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_transform: Option<PropertyValue<TextTransform>>,
+
+    /// Show the text even if it collides with other symbols
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_allow_overlap: Option<PropertyValue<bool>>,
+
+    /// Let other symbols overlap the text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_ignore_placement: Option<PropertyValue<bool>>,
+
+    /// Show the icon even if the text collides
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_optional: Option<PropertyValue<bool>>,
+
+    /// Whether the layer is shown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<PropertyValue<Visibility>>,
+}
+
+/// Paint properties for `symbol` layers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct SymbolPaint {
+    /// Icon opacity (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_opacity: Option<PropertyValue<f64>>,
+
+    /// Icon color (SDF icons only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_color: Option<PropertyValue<String>>,
+
+    /// Icon halo color (SDF icons only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_halo_color: Option<PropertyValue<String>>,
+
+    /// Icon halo width in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_halo_width: Option<PropertyValue<f64>>,
+
+    /// Text opacity (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_opacity: Option<PropertyValue<f64>>,
+
+    /// Text color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<PropertyValue<String>>,
+
+    /// Text halo color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_halo_color: Option<PropertyValue<String>>,
+
+    /// Text halo width in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_halo_width: Option<PropertyValue<f64>>,
+
+    /// Text halo blur in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_halo_blur: Option<PropertyValue<f64>>,
+}
+
+/// Paint properties for `fill-extrusion` layers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct FillExtrusionPaint {
+    /// Opacity of the whole layer (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_extrusion_opacity: Option<PropertyValue<f64>>,
+
+    /// Extrusion color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_extrusion_color: Option<PropertyValue<String>>,
+
+    /// Pixel offset `[x, y]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_extrusion_translate: Option<PropertyValue<[f64; 2]>>,
+
+    /// Image name used as a repeating pattern
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_extrusion_pattern: Option<PropertyValue<String>>,
+
+    /// Extrusion height in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_extrusion_height: Option<PropertyValue<f64>>,
+
+    /// Extrusion base height in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_extrusion_base: Option<PropertyValue<f64>>,
+
+    /// Whether sides are shaded with a vertical gradient
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_extrusion_vertical_gradient: Option<PropertyValue<bool>>,
+}
+
+/// Paint properties for `heatmap` layers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct HeatmapPaint {
+    /// Influence radius of each point in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heatmap_radius: Option<PropertyValue<f64>>,
+
+    /// Contribution of each point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heatmap_weight: Option<PropertyValue<f64>>,
+
+    /// Global intensity multiplier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heatmap_intensity: Option<PropertyValue<f64>>,
+
+    /// Color ramp, usually `Expr::interpolate` over `["heatmap-density"]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heatmap_color: Option<PropertyValue<String>>,
+
+    /// Opacity (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heatmap_opacity: Option<PropertyValue<f64>>,
+}
+
+/// Paint properties for `raster` layers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct RasterPaint {
+    /// Opacity (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raster_opacity: Option<PropertyValue<f64>>,
+
+    /// Hue rotation in degrees
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raster_hue_rotate: Option<PropertyValue<f64>>,
+
+    /// Minimum brightness (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raster_brightness_min: Option<PropertyValue<f64>>,
+
+    /// Maximum brightness (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raster_brightness_max: Option<PropertyValue<f64>>,
+
+    /// Saturation adjustment (-1 to 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raster_saturation: Option<PropertyValue<f64>>,
+
+    /// Contrast adjustment (-1 to 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raster_contrast: Option<PropertyValue<f64>>,
+
+    /// Resampling used when overscaling
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raster_resampling: Option<PropertyValue<RasterResampling>>,
+
+    /// Fade duration for new tiles in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raster_fade_duration: Option<PropertyValue<f64>>,
+}
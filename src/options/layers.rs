@@ -1,6 +1,12 @@
 //! Layer option model and builder helpers.
 
 use serde::{Deserialize, Serialize};
+
+use super::layer_properties::{
+    BackgroundPaint, CirclePaint, FillExtrusionPaint, FillPaint, HeatmapPaint, LayerType,
+    LineLayout, LinePaint, RasterPaint, SymbolLayout, SymbolPaint,
+};
+
 /// Options for adding a map layer
 ///
 /// # Examples
@@ -15,6 +21,19 @@ use serde::{Deserialize, Serialize};
 ///         "circle-color": "#3b82f6"
 ///     }));
 /// ```
+///
+/// Typed properties serialize to the same JSON:
+///
+/// ```
+/// use dioxus_maplibre::{CirclePaint, LayerOptions};
+///
+/// let layer = LayerOptions::circle("my-circles", "my-source")
+///     .circle_paint(CirclePaint {
+///         circle_radius: Some(6.0.into()),
+///         circle_color: Some("#3b82f6".into()),
+///         ..Default::default()
+///     });
+/// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[must_use]
@@ -24,7 +43,7 @@ pub struct LayerOptions {
 
     /// Layer type: circle, fill, line, symbol, fill-extrusion, heatmap, raster, background
     #[serde(rename = "type")]
-    pub layer_type: String,
+    pub layer_type: LayerType,
 
     /// Source ID
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -53,13 +72,39 @@ pub struct LayerOptions {
     /// Maximum zoom level for this layer
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_zoom: Option<f64>,
+
+    /// Insert below this layer; takes precedence over `slot` while it exists
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+
+    /// Insert at a named depth in the basemap, re-resolved after style switches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slot: Option<LayerSlot>,
+}
+
+/// A named insertion depth, resolved against the basemap's layers
+///
+/// Layers sharing a slot stack in the order they were added. When the basemap
+/// has no matching layers, road slots fall back to `BelowLabels` and
+/// `BelowLabels` falls back to the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LayerSlot {
+    /// Above the background, below every other basemap layer
+    Bottom,
+    /// Below the first road line layer
+    BelowRoads,
+    /// Above the last road line layer
+    AboveRoads,
+    /// Below the first symbol (label) layer
+    BelowLabels,
 }
 
 impl LayerOptions {
     /// Create a new layer with the given type
     pub fn new(
         id: impl Into<String>,
-        layer_type: impl Into<String>,
+        layer_type: impl Into<LayerType>,
         source: impl Into<String>,
     ) -> Self {
         Self {
@@ -72,49 +117,51 @@ impl LayerOptions {
             filter: None,
             min_zoom: None,
             max_zoom: None,
+            before_id: None,
+            slot: None,
         }
     }
 
     /// Create a circle layer
     pub fn circle(id: impl Into<String>, source: impl Into<String>) -> Self {
-        Self::new(id, "circle", source)
+        Self::new(id, LayerType::Circle, source)
     }
 
     /// Create a fill layer
     pub fn fill(id: impl Into<String>, source: impl Into<String>) -> Self {
-        Self::new(id, "fill", source)
+        Self::new(id, LayerType::Fill, source)
     }
 
     /// Create a line layer
     pub fn line(id: impl Into<String>, source: impl Into<String>) -> Self {
-        Self::new(id, "line", source)
+        Self::new(id, LayerType::Line, source)
     }
 
     /// Create a symbol layer
     pub fn symbol(id: impl Into<String>, source: impl Into<String>) -> Self {
-        Self::new(id, "symbol", source)
+        Self::new(id, LayerType::Symbol, source)
     }
 
     /// Create a fill-extrusion layer
     pub fn fill_extrusion(id: impl Into<String>, source: impl Into<String>) -> Self {
-        Self::new(id, "fill-extrusion", source)
+        Self::new(id, LayerType::FillExtrusion, source)
     }
 
     /// Create a heatmap layer
     pub fn heatmap(id: impl Into<String>, source: impl Into<String>) -> Self {
-        Self::new(id, "heatmap", source)
+        Self::new(id, LayerType::Heatmap, source)
     }
 
     /// Create a raster layer
     pub fn raster(id: impl Into<String>, source: impl Into<String>) -> Self {
-        Self::new(id, "raster", source)
+        Self::new(id, LayerType::Raster, source)
     }
 
     /// Create a background layer (no source needed)
     pub fn background(id: impl Into<String>) -> Self {
         Self {
             id: id.into(),
-            layer_type: "background".into(),
+            layer_type: LayerType::Background,
             source: None,
             source_layer: None,
             paint: None,
@@ -122,6 +169,8 @@ impl LayerOptions {
             filter: None,
             min_zoom: None,
             max_zoom: None,
+            before_id: None,
+            slot: None,
         }
     }
 
@@ -143,9 +192,9 @@ impl LayerOptions {
         self
     }
 
-    /// Set a filter expression
-    pub fn filter(mut self, filter: serde_json::Value) -> Self {
-        self.filter = Some(filter);
+    /// Set a filter expression (raw JSON or a typed `Expr`)
+    pub fn filter(mut self, filter: impl Into<serde_json::Value>) -> Self {
+        self.filter = Some(filter.into());
         self
     }
 
@@ -160,4 +209,82 @@ impl LayerOptions {
         self.max_zoom = Some(zoom);
         self
     }
+
+    /// Insert below an existing layer
+    pub fn before_id(mut self, id: impl Into<String>) -> Self {
+        self.before_id = Some(id.into());
+        self
+    }
+
+    /// Insert at a named depth in the basemap
+    pub fn slot(mut self, slot: LayerSlot) -> Self {
+        self.slot = Some(slot);
+        self
+    }
+
+    /// Set typed paint properties for a `background` layer
+    pub fn background_paint(self, paint: BackgroundPaint) -> Self {
+        self.typed_paint(paint)
+    }
+
+    /// Set typed paint properties for a `fill` layer
+    pub fn fill_paint(self, paint: FillPaint) -> Self {
+        self.typed_paint(paint)
+    }
+
+    /// Set typed paint properties for a `line` layer
+    pub fn line_paint(self, paint: LinePaint) -> Self {
+        self.typed_paint(paint)
+    }
+
+    /// Set typed layout properties for a `line` layer
+    pub fn line_layout(self, layout: LineLayout) -> Self {
+        self.typed_layout(layout)
+    }
+
+    /// Set typed paint properties for a `circle` layer
+    pub fn circle_paint(self, paint: CirclePaint) -> Self {
+        self.typed_paint(paint)
+    }
+
+    /// Set typed paint properties for a `symbol` layer
+    pub fn symbol_paint(self, paint: SymbolPaint) -> Self {
+        self.typed_paint(paint)
+    }
+
+    /// Set typed layout properties for a `symbol` layer
+    pub fn symbol_layout(self, layout: SymbolLayout) -> Self {
+        self.typed_layout(layout)
+    }
+
+    /// Set typed paint properties for a `fill-extrusion` layer
+    pub fn fill_extrusion_paint(self, paint: FillExtrusionPaint) -> Self {
+        self.typed_paint(paint)
+    }
+
+    /// Set typed paint properties for a `heatmap` layer
+    pub fn heatmap_paint(self, paint: HeatmapPaint) -> Self {
+        self.typed_paint(paint)
+    }
+
+    /// Set typed paint properties for a `raster` layer
+    pub fn raster_paint(self, paint: RasterPaint) -> Self {
+        self.typed_paint(paint)
+    }
+
+    fn typed_paint(self, paint: impl Serialize) -> Self {
+        self.paint(serde_json::to_value(paint).unwrap_or_default())
+    }
+
+    fn typed_layout(self, layout: impl Serialize) -> Self {
+        self.layout(serde_json::to_value(layout).unwrap_or_default())
+    }
+}
+
+/// Options for layer hover tracking (`MapHandle::on_layer_hover_with`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LayerHoverOptions {
+    /// Set a `hover: true` feature-state on the hovered feature and clear it
+    /// on change or leave. Features need IDs (see `generate_id`/`promote_id`).
+    pub feature_state: bool,
 }
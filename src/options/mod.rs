@@ -2,15 +2,24 @@
 
 mod atmosphere;
 mod controls;
+mod images;
+mod layer_properties;
 mod layers;
 mod navigation;
 mod overlays;
 mod queries;
 mod sources;
+mod style;
 
 pub use atmosphere::{FogOptions, SkyOptions, TerrainOptions};
 pub use controls::{ControlPosition, Padding};
-pub use layers::LayerOptions;
+pub use images::{StyleImageOptions, StyleImageSource};
+pub use layer_properties::{
+    Alignment, Anchor, BackgroundPaint, CirclePaint, CirclePitch, FillExtrusionPaint, FillPaint,
+    HeatmapPaint, LayerType, LineCap, LineJoin, LineLayout, LinePaint, PropertyValue, RasterPaint,
+    RasterResampling, SymbolLayout, SymbolPaint, SymbolPlacement, TextTransform, Visibility,
+};
+pub use layers::{LayerHoverOptions, LayerOptions, LayerSlot};
 pub use navigation::{EaseToOptions, FitBoundsOptions, FlyToOptions, JumpToOptions};
 pub use overlays::{MarkerOptions, PopupOptions};
 pub use queries::{FeatureIdentifier, QueryOptions};
@@ -18,3 +27,4 @@ pub use sources::{
     GeoJsonSourceOptions, ImageSourceOptions, RasterDemSourceOptions, RasterSourceOptions,
     VectorSourceOptions,
 };
+pub use style::{MapStyle, SetStyleOptions, StyleSpecification, StyleTransition};
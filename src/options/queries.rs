@@ -1,6 +1,9 @@
 //! Query and feature-state option models.
 
 use serde::{Deserialize, Serialize};
+
+use crate::types::FeatureId;
+
 /// Options for querying rendered or source features
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -21,8 +24,8 @@ pub struct FeatureIdentifier {
     /// Source ID
     pub source: String,
 
-    /// Feature ID (must be numeric for MapLibre feature state)
-    pub id: i64,
+    /// Feature ID
+    pub id: FeatureId,
 
     /// Source layer (required for vector tile sources)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,6 +33,16 @@ pub struct GeoJsonSourceOptions {
     pub promote_id: Option<String>,
 }
 
+impl GeoJsonSourceOptions {
+    /// Create options from raw JSON or a typed `Geometry`, `Feature` or `FeatureCollection`
+    pub fn new(data: impl Into<serde_json::Value>) -> Self {
+        Self {
+            data: data.into(),
+            ..Default::default()
+        }
+    }
+}
+
 /// Options for adding a vector tile source
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
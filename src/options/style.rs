@@ -0,0 +1,210 @@
+//! Style specification model.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::atmosphere::{SkyOptions, TerrainOptions};
+
+/// A complete MapLibre style document
+///
+/// Sources and layers stay as raw JSON so basemap styles round-trip through
+/// `MapHandle::get_style` without losing properties; keys not modelled here
+/// are kept in `extra`.
+///
+/// # Examples
+///
+/// ```
+/// use dioxus_maplibre::StyleSpecification;
+/// use serde_json::json;
+///
+/// let style = StyleSpecification::default()
+///     .source("osm", json!({
+///         "type": "raster",
+///         "tiles": ["https://tile.openstreetmap.org/{z}/{x}/{y}.png"],
+///         "tileSize": 256
+///     }))
+///     .layer(json!({ "id": "osm", "type": "raster", "source": "osm" }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub struct StyleSpecification {
+    /// Style spec version (always 8)
+    pub version: u8,
+
+    /// Human-readable style name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Arbitrary metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+
+    /// Default center as `[lng, lat]`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub center: Option<[f64; 2]>,
+
+    /// Default zoom
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zoom: Option<f64>,
+
+    /// Default bearing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bearing: Option<f64>,
+
+    /// Default pitch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pitch: Option<f64>,
+
+    /// Sources keyed by source ID (MapLibre source specifications)
+    #[serde(default)]
+    pub sources: BTreeMap<String, serde_json::Value>,
+
+    /// Layers in draw order (MapLibre layer specifications)
+    #[serde(default)]
+    pub layers: Vec<serde_json::Value>,
+
+    /// Sprite URL, or an array of `{ id, url }` sprites
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprite: Option<serde_json::Value>,
+
+    /// Glyphs URL template with `{fontstack}` and `{range}` tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub glyphs: Option<String>,
+
+    /// Global light
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub light: Option<serde_json::Value>,
+
+    /// Sky
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sky: Option<SkyOptions>,
+
+    /// 3D terrain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terrain: Option<TerrainOptions>,
+
+    /// Projection, e.g. `{ "type": "globe" }`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projection: Option<serde_json::Value>,
+
+    /// Default transition for property changes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transition: Option<StyleTransition>,
+
+    /// Top-level keys not modelled above
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Default for StyleSpecification {
+    fn default() -> Self {
+        Self {
+            version: 8,
+            name: None,
+            metadata: None,
+            center: None,
+            zoom: None,
+            bearing: None,
+            pitch: None,
+            sources: BTreeMap::new(),
+            layers: Vec::new(),
+            sprite: None,
+            glyphs: None,
+            light: None,
+            sky: None,
+            terrain: None,
+            projection: None,
+            transition: None,
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+impl StyleSpecification {
+    /// Add (or replace) a source
+    pub fn source(mut self, id: impl Into<String>, source: serde_json::Value) -> Self {
+        self.sources.insert(id.into(), source);
+        self
+    }
+
+    /// Append a layer on top of the existing ones
+    pub fn layer(mut self, layer: serde_json::Value) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// IDs of all layers, in draw order
+    pub fn layer_ids(&self) -> impl Iterator<Item = &str> {
+        self.layers
+            .iter()
+            .filter_map(|layer| layer.get("id")?.as_str())
+    }
+}
+
+/// Transition timing for style property changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct StyleTransition {
+    /// Duration in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+
+    /// Delay in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delay: Option<u32>,
+}
+
+/// A map style: a URL to a style document, or an inline specification
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapStyle {
+    /// URL of a style JSON document
+    Url(String),
+    /// Inline style document
+    Spec(Box<StyleSpecification>),
+}
+
+impl Default for MapStyle {
+    fn default() -> Self {
+        Self::Url("https://basemaps.cartocdn.com/gl/dark-matter-gl-style/style.json".to_string())
+    }
+}
+
+impl From<&str> for MapStyle {
+    fn from(value: &str) -> Self {
+        Self::Url(value.to_string())
+    }
+}
+
+impl From<String> for MapStyle {
+    fn from(value: String) -> Self {
+        Self::Url(value)
+    }
+}
+
+impl From<&String> for MapStyle {
+    fn from(value: &String) -> Self {
+        Self::Url(value.clone())
+    }
+}
+
+impl From<StyleSpecification> for MapStyle {
+    fn from(value: StyleSpecification) -> Self {
+        Self::Spec(Box::new(value))
+    }
+}
+
+/// How `MapHandle::set_style_with` (and `Map`'s `style_options`) switch styles
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetStyleOptions {
+    /// Diff against the current style and apply only what changed, instead of
+    /// swapping the style and replaying runtime sources, layers and images.
+    ///
+    /// Sources, layers, terrain and sky added through this crate are carried
+    /// into the next style, so they stay in place without a reload.
+    pub diff: bool,
+
+    /// JS function body run as `transformStyle(previous, next)` after the
+    /// runtime state is merged into `next`; return the style to apply.
+    /// Only used with `diff`.
+    pub transform_style: Option<String>,
+}
@@ -0,0 +1,149 @@
+//! Recording map backend for native unit tests.
+//!
+//! `MockMap` hands out a `MapHandle` whose mutating calls (fire-and-forget and
+//! `try_*`) are captured as typed [`MapCommand`]s instead of being evaluated
+//! as JS, lets tests script what `try_*` calls and async getters return, and
+//! feeds bridge events to [`MapEventHandlers`].
+//!
+//! ```rust,ignore
+//! let mock = MockMap::new();
+//! mock.handle().zoom_to(12.0);
+//! assert_eq!(mock.commands(), vec![MapCommand::ZoomTo(12.0)]);
+//!
+//! mock.respond("get_zoom", 12.0);
+//! assert_eq!(mock.handle().get_zoom().await, Some(12.0));
+//! ```
+
+use std::sync::{Arc, MutexGuard, PoisonError};
+
+use dioxus::prelude::{Signal, provide_context, try_consume_context, use_hook};
+use serde::Serialize;
+
+use crate::components::{LayerHandlerRegistry, MapHandleSignal, MapStateSignal};
+use crate::error::MapError;
+use crate::events::MapEvent;
+use crate::handle::{MapHandle, Recorder};
+use crate::types::MapState;
+
+pub use crate::components::MapEventHandlers;
+pub use crate::handle::MapCommand;
+
+/// A map stand-in that records every command sent through its handle.
+#[derive(Debug, Clone)]
+pub struct MockMap {
+    handle: MapHandle,
+    recorder: Arc<Recorder>,
+}
+
+impl MockMap {
+    /// Create a mock with the map ID `mock_map`
+    pub fn new() -> Self {
+        Self::with_map_id("mock_map")
+    }
+
+    /// Create a mock with a custom map ID
+    pub fn with_map_id(map_id: impl Into<String>) -> Self {
+        let recorder = Arc::new(Recorder::default());
+        Self {
+            handle: MapHandle::recording(map_id.into(), Arc::clone(&recorder)),
+            recorder,
+        }
+    }
+
+    /// A handle that records into this mock
+    pub fn handle(&self) -> MapHandle {
+        self.handle.clone()
+    }
+
+    /// All commands recorded so far, oldest first
+    pub fn commands(&self) -> Vec<MapCommand> {
+        self.lock().clone()
+    }
+
+    /// Drain the recorded commands
+    pub fn take_commands(&self) -> Vec<MapCommand> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    /// Forget all recorded commands
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Script the value returned by the next call to `method`
+    ///
+    /// `method` is the `MapHandle` method name, e.g. `"get_zoom"`,
+    /// `"query_rendered_features"` or `"try_add_layer"`. Responses queue up
+    /// per method. Unscripted getters return `None` (or an empty/false
+    /// value) and unscripted `try_*` calls succeed.
+    pub fn respond(&self, method: &str, value: impl Serialize) {
+        let value = serde_json::to_value(value).unwrap_or_default();
+        self.push_response(method, Ok(value));
+    }
+
+    /// Script an error for the next call to the `try_*` method `method`
+    pub fn fail(&self, method: &str, error: MapError) {
+        self.push_response(method, Err(error));
+    }
+
+    fn push_response(&self, method: &str, response: Result<serde_json::Value, MapError>) {
+        self.recorder
+            .responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(method.to_string())
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Route `event` through `handlers` as the `Map` component would.
+    ///
+    /// Must run inside a Dioxus runtime. Uses the map context installed by
+    /// [`use_mock_map`] when present, so `use_map_handle`, `use_map_state` and
+    /// `MapLayer` handlers observe the event too.
+    pub fn dispatch(&self, handlers: &MapEventHandlers, event: MapEvent) {
+        let handle_signal = try_consume_context::<MapHandleSignal>()
+            .unwrap_or_else(|| Signal::new(Some(self.handle())));
+        let mut handlers = handlers.clone();
+        if handlers.state.is_none() {
+            handlers.state = try_consume_context::<MapStateSignal>();
+        }
+        if handlers.layers.is_none() {
+            handlers.layers = try_consume_context::<LayerHandlerRegistry>();
+        }
+        handlers.dispatch(&self.handle, event, handle_signal);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<MapCommand>> {
+        self.recorder
+            .commands
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Mocks are equal when they share the same command log.
+impl PartialEq for MockMap {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.recorder, &other.recorder)
+    }
+}
+
+impl Default for MockMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Provide `mock` as the map context for the calling component's subtree.
+///
+/// Descendants see it through `use_map_handle`, `use_map_state` and the
+/// declarative components (`MapSource`, `MapLayer`, ...), whose calls are then
+/// recorded. Only the mock passed on the first render is installed.
+pub fn use_mock_map(mock: &MockMap) {
+    use_hook(|| {
+        provide_context::<MapHandleSignal>(Signal::new(Some(mock.handle())));
+        provide_context::<MapStateSignal>(Signal::new(MapState::default()));
+        provide_context(LayerHandlerRegistry::default());
+    });
+}
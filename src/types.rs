@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::options::Padding;
+
 /// A geographic coordinate (latitude/longitude pair)
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
 pub struct LatLng {
@@ -36,27 +38,50 @@ impl LatLng {
     }
 }
 
-/// Map position (center + zoom)
+/// Map camera position (center, zoom, bearing, pitch)
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct MapPosition {
     /// Center coordinate
     pub center: LatLng,
     /// Zoom level (0-22)
     pub zoom: f64,
+    /// Bearing in degrees (0-360)
+    #[serde(default)]
+    pub bearing: f64,
+    /// Pitch in degrees (0-85)
+    #[serde(default)]
+    pub pitch: f64,
 }
 
 impl MapPosition {
+    /// Create a north-up, untilted position
     pub fn new(center: LatLng, zoom: f64) -> Self {
-        Self { center, zoom }
+        Self {
+            center,
+            zoom,
+            bearing: 0.0,
+            pitch: 0.0,
+        }
+    }
+
+    /// Set the bearing in degrees
+    #[must_use]
+    pub fn with_bearing(mut self, bearing: f64) -> Self {
+        self.bearing = bearing;
+        self
+    }
+
+    /// Set the pitch in degrees
+    #[must_use]
+    pub fn with_pitch(mut self, pitch: f64) -> Self {
+        self.pitch = pitch;
+        self
     }
 }
 
 impl Default for MapPosition {
     fn default() -> Self {
-        Self {
-            center: LatLng::helsinki(),
-            zoom: 10.0,
-        }
+        Self::new(LatLng::helsinki(), 10.0)
     }
 }
 
@@ -82,6 +107,15 @@ impl Bounds {
             && point.lng <= self.ne.lng
     }
 
+    /// Return the smallest bounds containing both these bounds and `point`
+    #[must_use]
+    pub fn extend(&self, point: LatLng) -> Self {
+        Self {
+            sw: LatLng::new(self.sw.lat.min(point.lat), self.sw.lng.min(point.lng)),
+            ne: LatLng::new(self.ne.lat.max(point.lat), self.ne.lng.max(point.lng)),
+        }
+    }
+
     /// Get the center of the bounds
     pub fn center(&self) -> LatLng {
         LatLng {
@@ -91,15 +125,430 @@ impl Bounds {
     }
 }
 
+/// Snapshot of the live map camera and loading status, as tracked by `use_map_state`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MapState {
+    /// Center coordinate
+    pub center: LatLng,
+    /// Zoom level (0-22)
+    pub zoom: f64,
+    /// Bearing in degrees (0-360)
+    pub bearing: f64,
+    /// Pitch in degrees (0-85)
+    pub pitch: f64,
+    /// Visible bounds, once the map has reported them
+    pub bounds: Option<Bounds>,
+    /// Viewport padding
+    pub padding: Padding,
+    /// Whether the camera is currently moving
+    pub is_moving: bool,
+    /// Whether the style has finished loading
+    pub is_style_loaded: bool,
+    /// Whether all visible tiles have loaded
+    pub tiles_loaded: bool,
+}
+
+impl MapState {
+    /// The camera part of this state
+    pub fn position(&self) -> MapPosition {
+        MapPosition {
+            center: self.center,
+            zoom: self.zoom,
+            bearing: self.bearing,
+            pitch: self.pitch,
+        }
+    }
+}
+
+impl From<MapPosition> for MapState {
+    fn from(position: MapPosition) -> Self {
+        Self {
+            center: position.center,
+            zoom: position.zoom,
+            bearing: position.bearing,
+            pitch: position.pitch,
+            bounds: None,
+            padding: Padding::default(),
+            is_moving: false,
+            is_style_loaded: false,
+            tiles_loaded: false,
+        }
+    }
+}
+
+impl Default for MapState {
+    fn default() -> Self {
+        MapPosition::default().into()
+    }
+}
+
+/// A GeoJSON position, serialized as `[lng, lat]` or `[lng, lat, altitude]`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Position {
+    /// Longitude in degrees
+    pub lng: f64,
+    /// Latitude in degrees
+    pub lat: f64,
+    /// Optional altitude in meters
+    pub altitude: Option<f64>,
+}
+
+impl Position {
+    /// Create a 2D position
+    pub fn new(lng: f64, lat: f64) -> Self {
+        Self {
+            lng,
+            lat,
+            altitude: None,
+        }
+    }
+
+    /// Convert to a `LatLng`, dropping any altitude
+    pub fn to_latlng(&self) -> LatLng {
+        LatLng::new(self.lat, self.lng)
+    }
+}
+
+impl Serialize for Position {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.altitude {
+            Some(altitude) => [self.lng, self.lat, altitude].serialize(serializer),
+            None => [self.lng, self.lat].serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Position {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<f64>::deserialize(deserializer)?;
+        match values.as_slice() {
+            [lng, lat] => Ok(Self::new(*lng, *lat)),
+            [lng, lat, altitude, ..] => Ok(Self {
+                lng: *lng,
+                lat: *lat,
+                altitude: Some(*altitude),
+            }),
+            _ => Err(serde::de::Error::invalid_length(
+                values.len(),
+                &"a position with at least 2 elements",
+            )),
+        }
+    }
+}
+
+/// A GeoJSON geometry object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Geometry {
+    Point {
+        coordinates: Position,
+    },
+    MultiPoint {
+        coordinates: Vec<Position>,
+    },
+    LineString {
+        coordinates: Vec<Position>,
+    },
+    MultiLineString {
+        coordinates: Vec<Vec<Position>>,
+    },
+    /// Rings of positions; the first ring is the exterior, the rest are holes
+    Polygon {
+        coordinates: Vec<Vec<Position>>,
+    },
+    MultiPolygon {
+        coordinates: Vec<Vec<Vec<Position>>>,
+    },
+    GeometryCollection {
+        geometries: Vec<Self>,
+    },
+}
+
+impl Geometry {
+    /// Create a point geometry
+    pub fn point(position: LatLng) -> Self {
+        Self::Point {
+            coordinates: position.into(),
+        }
+    }
+
+    /// Create a line string geometry from a list of coordinates
+    pub fn line_string(positions: impl IntoIterator<Item = LatLng>) -> Self {
+        Self::LineString {
+            coordinates: positions.into_iter().map(Position::from).collect(),
+        }
+    }
+
+    /// Create a polygon geometry from a single exterior ring
+    ///
+    /// The ring is closed automatically if its last coordinate differs from the first.
+    pub fn polygon(ring: impl IntoIterator<Item = LatLng>) -> Self {
+        let mut ring: Vec<Position> = ring.into_iter().map(Position::from).collect();
+        if let (Some(first), Some(last)) = (ring.first().copied(), ring.last())
+            && first != *last
+        {
+            ring.push(first);
+        }
+        Self::Polygon {
+            coordinates: vec![ring],
+        }
+    }
+
+    /// Get the coordinate of a `Point` geometry
+    pub fn as_point(&self) -> Option<LatLng> {
+        match self {
+            Self::Point { coordinates } => Some(coordinates.to_latlng()),
+            _ => None,
+        }
+    }
+
+    /// Compute the bounding box of all positions, or `None` if the geometry is empty
+    pub fn bounds(&self) -> Option<Bounds> {
+        let mut bounds: Option<Bounds> = None;
+        self.visit_positions(&mut |position| {
+            let point = position.to_latlng();
+            bounds = Some(match bounds {
+                Some(current) => current.extend(point),
+                None => Bounds::new(point, point),
+            });
+        });
+        bounds
+    }
+
+    fn visit_positions(&self, visit: &mut impl FnMut(&Position)) {
+        match self {
+            Self::Point { coordinates } => visit(coordinates),
+            Self::MultiPoint { coordinates } | Self::LineString { coordinates } => {
+                coordinates.iter().for_each(visit);
+            }
+            Self::MultiLineString { coordinates } | Self::Polygon { coordinates } => {
+                coordinates.iter().flatten().for_each(visit);
+            }
+            Self::MultiPolygon { coordinates } => {
+                coordinates.iter().flatten().flatten().for_each(visit);
+            }
+            Self::GeometryCollection { geometries } => {
+                for geometry in geometries {
+                    geometry.visit_positions(visit);
+                }
+            }
+        }
+    }
+}
+
+impl From<LatLng> for Position {
+    fn from(value: LatLng) -> Self {
+        Self::new(value.lng, value.lat)
+    }
+}
+
+impl From<Bounds> for Geometry {
+    /// The bounds as a closed rectangular polygon ring
+    fn from(value: Bounds) -> Self {
+        let Bounds { sw, ne } = value;
+        Self::Polygon {
+            coordinates: vec![vec![
+                Position::new(sw.lng, sw.lat),
+                Position::new(ne.lng, sw.lat),
+                Position::new(ne.lng, ne.lat),
+                Position::new(sw.lng, ne.lat),
+                Position::new(sw.lng, sw.lat),
+            ]],
+        }
+    }
+}
+
+impl From<LatLng> for Geometry {
+    fn from(value: LatLng) -> Self {
+        Self::point(value)
+    }
+}
+
+/// A feature ID: numeric, or a string (e.g. promoted with `promoteId`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FeatureId {
+    /// Numeric ID
+    Number(i64),
+    /// String ID
+    String(String),
+}
+
+impl FeatureId {
+    /// The numeric ID, if this is a number
+    pub fn as_number(&self) -> Option<i64> {
+        match self {
+            Self::Number(id) => Some(*id),
+            Self::String(_) => None,
+        }
+    }
+
+    /// The string ID, if this is a string
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Number(_) => None,
+            Self::String(id) => Some(id),
+        }
+    }
+}
+
+impl std::fmt::Display for FeatureId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(id) => write!(f, "{id}"),
+            Self::String(id) => f.write_str(id),
+        }
+    }
+}
+
+impl From<i64> for FeatureId {
+    fn from(value: i64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<i32> for FeatureId {
+    fn from(value: i32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<u32> for FeatureId {
+    fn from(value: u32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<&str> for FeatureId {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for FeatureId {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+/// A GeoJSON feature with typed properties
+///
+/// `P` defaults to `serde_json::Value`; use your own `Serialize`/`Deserialize`
+/// struct to work with typed properties. Deserializing also needs `Default`,
+/// used when properties are missing or `null`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename = "Feature",
+    bound(deserialize = "P: Deserialize<'de> + Default")
+)]
+pub struct Feature<P = serde_json::Value> {
+    /// Feature ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<FeatureId>,
+    /// Feature geometry (`null` for features without a location)
+    pub geometry: Option<Geometry>,
+    /// Feature properties (`P::default()` when missing or `null`)
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub properties: P,
+}
+
+/// Deserialize `null` as the type's default value
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Option::<T>::deserialize(deserializer).map(Option::unwrap_or_default)
+}
+
+impl<P> Feature<P> {
+    /// Create a feature from a geometry and properties
+    pub fn new(geometry: impl Into<Geometry>, properties: P) -> Self {
+        Self {
+            id: None,
+            geometry: Some(geometry.into()),
+            properties,
+        }
+    }
+
+    /// Set the feature ID
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<FeatureId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+/// A GeoJSON feature collection with typed properties
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename = "FeatureCollection",
+    bound(deserialize = "P: Deserialize<'de> + Default")
+)]
+pub struct FeatureCollection<P = serde_json::Value> {
+    /// Features in the collection
+    pub features: Vec<Feature<P>>,
+}
+
+impl<P> FeatureCollection<P> {
+    /// Create a feature collection
+    pub fn new(features: Vec<Feature<P>>) -> Self {
+        Self { features }
+    }
+
+    /// Compute the bounding box of all feature geometries
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.features
+            .iter()
+            .filter_map(|feature| feature.geometry.as_ref()?.bounds())
+            .reduce(|acc, bounds| acc.extend(bounds.sw).extend(bounds.ne))
+    }
+}
+
+impl<P> Default for FeatureCollection<P> {
+    fn default() -> Self {
+        Self {
+            features: Vec::new(),
+        }
+    }
+}
+
+impl<P> FromIterator<Feature<P>> for FeatureCollection<P> {
+    fn from_iter<I: IntoIterator<Item = Feature<P>>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl From<Geometry> for serde_json::Value {
+    fn from(value: Geometry) -> Self {
+        serde_json::to_value(value).unwrap_or_default()
+    }
+}
+
+impl<P: Serialize> From<Feature<P>> for serde_json::Value {
+    fn from(value: Feature<P>) -> Self {
+        serde_json::to_value(value).unwrap_or_default()
+    }
+}
+
+impl<P: Serialize> From<FeatureCollection<P>> for serde_json::Value {
+    fn from(value: FeatureCollection<P>) -> Self {
+        serde_json::to_value(value).unwrap_or_default()
+    }
+}
+
 /// A feature returned by query methods (`queryRenderedFeatures`, `querySourceFeatures`)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryFeature {
-    /// Feature ID (numeric, if present)
+    /// Feature ID, if present
+    #[serde(default)]
+    pub id: Option<FeatureId>,
+    /// GeoJSON geometry (`None` for features without a location)
     #[serde(default)]
-    pub id: Option<i64>,
-    /// GeoJSON geometry
-    pub geometry: serde_json::Value,
+    pub geometry: Option<Geometry>,
     /// Feature properties
     pub properties: serde_json::Value,
     /// Source ID
@@ -107,6 +556,24 @@ pub struct QueryFeature {
     /// Source layer (for vector tile sources)
     #[serde(default)]
     pub source_layer: Option<String>,
+    /// Feature state set through `MapHandle::set_feature_state`
+    #[serde(default)]
+    pub state: serde_json::Value,
+}
+
+impl QueryFeature {
+    /// Convert into a GeoJSON `Feature`, deserializing properties into `P`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the properties do not match `P`.
+    pub fn into_feature<P: serde::de::DeserializeOwned>(self) -> serde_json::Result<Feature<P>> {
+        Ok(Feature {
+            id: self.id,
+            geometry: self.geometry,
+            properties: serde_json::from_value(self.properties)?,
+        })
+    }
 }
 
 /// A point in screen pixel coordinates
@@ -0,0 +1,51 @@
+//! Unit tests for map error types
+
+use dioxus_maplibre::MapError;
+
+#[test]
+fn map_error_deserializes_from_bridge_kind() {
+    let error: MapError =
+        serde_json::from_str(r#"{ "kind": "source_exists", "id": "points" }"#).unwrap();
+    assert_eq!(
+        error,
+        MapError::SourceExists {
+            id: "points".to_string()
+        }
+    );
+}
+
+#[test]
+fn map_error_js_exception_keeps_message() {
+    let error: MapError =
+        serde_json::from_str(r#"{ "kind": "js_exception", "message": "boom" }"#).unwrap();
+    let MapError::JsException { message } = error else {
+        panic!("expected js_exception variant");
+    };
+    assert_eq!(message, "boom");
+}
+
+#[test]
+fn map_error_map_not_found_roundtrip() {
+    let error = MapError::MapNotFound {
+        map_id: "map_1".to_string(),
+    };
+    let json = serde_json::to_string(&error).unwrap();
+    assert!(json.contains(r#""kind":"map_not_found""#));
+    let restored: MapError = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, error);
+}
+
+#[test]
+fn map_error_image_exists_from_bridge_kind() {
+    let error: MapError =
+        serde_json::from_str(r#"{ "kind": "image_exists", "id": "pin" }"#).unwrap();
+    assert_eq!(error.to_string(), "image already exists: pin");
+}
+
+#[test]
+fn map_error_display() {
+    let error = MapError::LayerMissing {
+        id: "roads".to_string(),
+    };
+    assert_eq!(error.to_string(), "layer not found: roads");
+}
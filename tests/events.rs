@@ -2,9 +2,10 @@
 #![allow(clippy::float_cmp, clippy::unreadable_literal)]
 
 use dioxus_maplibre::{
-    LatLng, MapClickEvent, MapContextMenuEvent, MapDblClickEvent, MapErrorEvent, MapEvent,
-    MapMoveEvent, MapPitchEvent, MapRotateEvent, MapZoomEvent, MarkerClickEvent,
-    MarkerDragEndEvent, MarkerDragStartEvent, MarkerHoverEvent, Point,
+    LatLng, LayerClickEvent, LayerHoverPhase, MapClickEvent, MapContextMenuEvent, MapDataType,
+    MapDblClickEvent, MapErrorEvent, MapEvent, MapMoveEvent, MapPitchEvent, MapRotateEvent,
+    MapZoomEvent, MarkerClickEvent, MarkerDragEndEvent, MarkerDragStartEvent, MarkerHoverEvent,
+    ModifierKeys, MovePhase, Padding, Point,
 };
 
 #[test]
@@ -105,6 +106,101 @@ fn map_move_event_deserialize() {
     let event: MapMoveEvent = serde_json::from_str(json).unwrap();
     assert_eq!(event.center.lat, 60.17);
     assert_eq!(event.zoom, 12.5);
+    assert_eq!(event.bearing, 0.0);
+}
+
+#[test]
+fn map_move_event_deserialize_camera() {
+    let json = r#"{
+        "center": {"lat": 60.17, "lng": 24.94},
+        "zoom": 12.5,
+        "bearing": 45.0,
+        "pitch": 30.0
+    }"#;
+
+    let event: MapMoveEvent = serde_json::from_str(json).unwrap();
+    assert_eq!(event.bearing, 45.0);
+    assert_eq!(event.pitch, 30.0);
+}
+
+#[test]
+fn map_move_event_deserialize_padding() {
+    let json = r#"{
+        "center": {"lat": 60.17, "lng": 24.94},
+        "zoom": 12.5,
+        "padding": {"top": 10.0, "bottom": 0.0, "left": 20.0, "right": 0.0}
+    }"#;
+
+    let event: MapMoveEvent = serde_json::from_str(json).unwrap();
+    let padding = event.padding;
+    assert_eq!(padding.top, 10.0);
+    assert_eq!(padding.left, 20.0);
+}
+
+#[test]
+fn map_move_event_phase_and_seq_from_js_format() {
+    let parse = |phase: &str, seq: u64| -> MapMoveEvent {
+        serde_json::from_str(&format!(
+            r#"{{
+                "center": {{"lat": 60.17, "lng": 24.94}},
+                "zoom": 12.5,
+                "phase": "{phase}",
+                "seq": {seq}
+            }}"#
+        ))
+        .unwrap()
+    };
+    assert_eq!(parse("move", 3).phase, MovePhase::Move);
+    assert_eq!(parse("moveend", 4).phase, MovePhase::MoveEnd);
+    let load = parse("move_load", 1);
+    assert_eq!(load.phase, MovePhase::Load);
+    assert_eq!(load.seq, 1);
+}
+
+#[test]
+fn map_move_event_snapshot_defaults() {
+    let json = r#"{"center": {"lat": 60.17, "lng": 24.94}, "zoom": 12.5}"#;
+    let event: MapMoveEvent = serde_json::from_str(json).unwrap();
+    assert_eq!(event.phase, MovePhase::Move);
+    assert_eq!(event.seq, 0);
+    assert_eq!(event.padding, Padding::default());
+    assert_eq!(event.position().zoom, 12.5);
+    assert!(!event.user_initiated);
+}
+
+#[test]
+fn camera_end_events_carry_user_initiated() {
+    let json = r#"{"type": "zoom", "zoom": 14.0, "user_initiated": true}"#;
+    let MapEvent::Zoom(zoom) = serde_json::from_str(json).unwrap() else {
+        panic!("expected zoom variant");
+    };
+    assert!(zoom.user_initiated);
+
+    let json = r#"{"type": "pitch", "pitch": 30.0}"#;
+    let MapEvent::Pitch(pitch) = serde_json::from_str(json).unwrap() else {
+        panic!("expected pitch variant");
+    };
+    assert!(!pitch.user_initiated);
+
+    let json = r#"{"type": "move", "center": {"lat": 0.0, "lng": 0.0}, "zoom": 2.0,
+        "phase": "moveend", "user_initiated": true}"#;
+    let MapEvent::Move(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected move variant");
+    };
+    assert!(event.user_initiated);
+}
+
+#[test]
+fn map_event_load_state_deserialize() {
+    let json = r#"{"type": "load_state", "style_loaded": true, "tiles_loaded": false}"#;
+    let event: MapEvent = serde_json::from_str(json).unwrap();
+    match event {
+        MapEvent::LoadState(e) => {
+            assert!(e.style_loaded);
+            assert!(!e.tiles_loaded);
+        }
+        _ => panic!("Expected LoadState event"),
+    }
 }
 
 #[test]
@@ -112,8 +208,13 @@ fn map_move_event_serialize() {
     let event = MapMoveEvent {
         center: LatLng::new(60.17, 24.94),
         zoom: 10.0,
+        bearing: 0.0,
+        pitch: 0.0,
+        padding: Padding::default(),
         bounds: None,
-        phase: None,
+        phase: MovePhase::Move,
+        seq: 0,
+        user_initiated: false,
     };
 
     let json = serde_json::to_string(&event).unwrap();
@@ -139,15 +240,22 @@ fn map_event_error_deserialize() {
 
 #[test]
 fn map_zoom_event_roundtrip() {
-    let event = MapZoomEvent { zoom: 15.5 };
+    let event = MapZoomEvent {
+        zoom: 15.5,
+        user_initiated: true,
+    };
     let json = serde_json::to_string(&event).unwrap();
     let restored: MapZoomEvent = serde_json::from_str(&json).unwrap();
     assert_eq!(restored.zoom, 15.5);
+    assert!(restored.user_initiated);
 }
 
 #[test]
 fn map_rotate_event_roundtrip() {
-    let event = MapRotateEvent { bearing: 45.0 };
+    let event = MapRotateEvent {
+        bearing: 45.0,
+        user_initiated: false,
+    };
     let json = serde_json::to_string(&event).unwrap();
     let restored: MapRotateEvent = serde_json::from_str(&json).unwrap();
     assert_eq!(restored.bearing, 45.0);
@@ -155,7 +263,10 @@ fn map_rotate_event_roundtrip() {
 
 #[test]
 fn map_pitch_event_roundtrip() {
-    let event = MapPitchEvent { pitch: 60.0 };
+    let event = MapPitchEvent {
+        pitch: 60.0,
+        user_initiated: false,
+    };
     let json = serde_json::to_string(&event).unwrap();
     let restored: MapPitchEvent = serde_json::from_str(&json).unwrap();
     assert_eq!(restored.pitch, 60.0);
@@ -212,3 +323,293 @@ fn marker_dragend_from_js_format() {
     assert_eq!(event.latlng.lat, 60.2);
     assert_eq!(event.latlng.lng, 24.8);
 }
+
+#[test]
+fn map_event_mouse_from_js_format() {
+    let json = r#"{
+        "type": "mousedown",
+        "latlng": {"lat": 60.17, "lng": 24.94},
+        "point": {"x": 10.0, "y": 20.0},
+        "button": 2,
+        "modifiers": {"alt": false, "ctrl": true, "meta": false, "shift": true}
+    }"#;
+    let MapEvent::MouseDown(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected mousedown variant");
+    };
+    assert_eq!(event.button, 2);
+    assert_eq!(event.point.y, 20.0);
+    assert!(event.modifiers.ctrl && event.modifiers.shift);
+    assert!(event.modifiers.any());
+}
+
+#[test]
+fn map_event_mouse_defaults_modifiers() {
+    let json = r#"{
+        "type": "mousemove",
+        "latlng": {"lat": 60.17, "lng": 24.94},
+        "point": {"x": 10.0, "y": 20.0}
+    }"#;
+    let MapEvent::MouseMove(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected mousemove variant");
+    };
+    assert_eq!(event.button, 0);
+    assert_eq!(event.modifiers, ModifierKeys::default());
+    assert!(!event.modifiers.any());
+}
+
+#[test]
+fn map_event_touch_from_js_format() {
+    let json = r#"{
+        "type": "touchmove",
+        "latlng": {"lat": 60.0, "lng": 25.0},
+        "point": {"x": 50.0, "y": 50.0},
+        "latlngs": [{"lat": 59.9, "lng": 24.9}, {"lat": 60.1, "lng": 25.1}],
+        "points": [{"x": 40.0, "y": 40.0}, {"x": 60.0, "y": 60.0}],
+        "modifiers": {}
+    }"#;
+    let MapEvent::TouchMove(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected touchmove variant");
+    };
+    assert_eq!(event.points.len(), 2);
+    assert_eq!(event.latlngs[1].lng, 25.1);
+    assert_eq!(event.latlng.lat, 60.0);
+}
+
+#[test]
+fn map_event_wheel_from_js_format() {
+    let json = r#"{
+        "type": "wheel",
+        "latlng": {"lat": 60.0, "lng": 25.0},
+        "point": {"x": 5.0, "y": 6.0},
+        "delta_x": 0.0,
+        "delta_y": -240.0,
+        "delta_mode": 0,
+        "modifiers": {"alt": false, "ctrl": true, "meta": false, "shift": false}
+    }"#;
+    let MapEvent::Wheel(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected wheel variant");
+    };
+    assert_eq!(event.delta_y, -240.0);
+    assert_eq!(event.delta_mode, 0);
+    assert!(event.modifiers.ctrl);
+}
+
+#[test]
+fn map_event_sourcedata_from_js_format() {
+    let json = r#"{
+        "type": "sourcedata",
+        "data_type": "source",
+        "source_id": "points",
+        "source_data_type": "metadata",
+        "is_source_loaded": true
+    }"#;
+    let MapEvent::SourceData(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected sourcedata variant");
+    };
+    assert_eq!(event.data_type, MapDataType::Source);
+    assert_eq!(event.source_id.as_deref(), Some("points"));
+    assert_eq!(event.source_data_type.as_deref(), Some("metadata"));
+    assert_eq!(event.is_source_loaded, Some(true));
+}
+
+#[test]
+fn map_event_styledata_without_source_fields() {
+    let json = r#"{
+        "type": "styledata",
+        "data_type": "style",
+        "source_id": null,
+        "source_data_type": null,
+        "is_source_loaded": null
+    }"#;
+    let MapEvent::StyleData(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected styledata variant");
+    };
+    assert_eq!(event.data_type, MapDataType::Style);
+    assert!(event.source_id.is_none());
+    assert!(event.is_source_loaded.is_none());
+}
+
+#[test]
+fn map_data_type_unknown_falls_back_to_other() {
+    let data_type: MapDataType = serde_json::from_str(r#""terrain""#).unwrap();
+    assert_eq!(data_type, MapDataType::Other);
+}
+
+#[test]
+fn map_event_lifecycle_unit_variants_deserialize() {
+    let idle: MapEvent = serde_json::from_str(r#"{ "type": "idle" }"#).unwrap();
+    assert!(matches!(idle, MapEvent::Idle));
+    let lost: MapEvent = serde_json::from_str(r#"{ "type": "webglcontextlost" }"#).unwrap();
+    assert!(matches!(lost, MapEvent::WebGlContextLost));
+}
+
+#[test]
+fn map_event_resize_and_missing_image_deserialize() {
+    let resize: MapEvent =
+        serde_json::from_str(r#"{ "type": "resize", "width": 800, "height": 600 }"#).unwrap();
+    let MapEvent::Resize(resize) = resize else {
+        panic!("expected resize variant");
+    };
+    assert_eq!(resize.width, 800.0);
+    assert_eq!(resize.height, 600.0);
+
+    let missing: MapEvent =
+        serde_json::from_str(r#"{ "type": "styleimagemissing", "id": "cafe-icon" }"#).unwrap();
+    let MapEvent::StyleImageMissing(missing) = missing else {
+        panic!("expected styleimagemissing variant");
+    };
+    assert_eq!(missing.id, "cafe-icon");
+}
+
+#[test]
+fn map_event_gesture_from_js_format() {
+    let json = r#"{
+        "type": "movestart",
+        "user_initiated": true,
+        "center": {"lat": 60.17, "lng": 24.94},
+        "zoom": 11.5,
+        "bearing": 15.0,
+        "pitch": 30.0
+    }"#;
+    let MapEvent::MoveStart(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected movestart variant");
+    };
+    assert!(event.user_initiated);
+    assert_eq!(event.zoom, 11.5);
+    assert_eq!(event.bearing, 15.0);
+}
+
+#[test]
+fn map_event_gesture_variants_are_distinct() {
+    let payload = r#""user_initiated": false, "center": {"lat": 0.0, "lng": 0.0}, "zoom": 1.0"#;
+    let parse = |kind: &str| -> MapEvent {
+        serde_json::from_str(&format!(r#"{{ "type": "{kind}", {payload} }}"#)).unwrap()
+    };
+    assert!(matches!(parse("zoomstart"), MapEvent::ZoomStart(e) if !e.user_initiated));
+    assert!(matches!(parse("dragstart"), MapEvent::DragStart(_)));
+    assert!(matches!(parse("drag"), MapEvent::Drag(_)));
+    assert!(matches!(parse("dragend"), MapEvent::DragEnd(_)));
+    assert!(matches!(parse("boxzoomend"), MapEvent::BoxZoomEnd(_)));
+}
+
+#[test]
+fn map_event_layer_pointer_variants_deserialize() {
+    let payload = r#""layer_id": "parks", "feature_id": 3, "properties": {}, "latlng": {"lat": 60.0, "lng": 25.0}"#;
+    let parse = |kind: &str| -> MapEvent {
+        serde_json::from_str(&format!(r#"{{ "type": "{kind}", {payload} }}"#)).unwrap()
+    };
+    assert!(matches!(parse("layer_dblclick"), MapEvent::LayerDblClick(e) if e.layer_id == "parks"));
+    assert!(
+        matches!(parse("layer_contextmenu"), MapEvent::LayerContextMenu(e) if e.feature_id == Some(3.into()))
+    );
+}
+
+#[test]
+fn layer_click_event_carries_all_hit_features() {
+    let json = r#"{
+        "type": "layer_click",
+        "layer_id": "parks",
+        "feature_id": 1,
+        "properties": {"name": "Esplanadi"},
+        "latlng": {"lat": 60.167, "lng": 24.948},
+        "point": {"x": 320.0, "y": 240.0},
+        "modifiers": {"shift": true},
+        "features": [
+            {
+                "id": 1,
+                "geometry": {"type": "Point", "coordinates": [24.948, 60.167]},
+                "properties": {"name": "Esplanadi"},
+                "source": "parks-source",
+                "sourceLayer": null,
+                "state": {"selected": true}
+            },
+            {
+                "id": 2,
+                "geometry": {"type": "Point", "coordinates": [24.949, 60.167]},
+                "properties": {"name": "Kappeli"},
+                "source": "parks-source",
+                "sourceLayer": "poi",
+                "state": {}
+            }
+        ]
+    }"#;
+    let MapEvent::LayerClick(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected layer_click variant");
+    };
+    assert_eq!(event.point.x, 320.0);
+    assert!(event.modifiers.shift);
+    assert_eq!(event.features.len(), 2);
+    assert_eq!(event.features[0].state["selected"], true);
+    assert_eq!(event.features[1].source_layer.as_deref(), Some("poi"));
+}
+
+#[test]
+fn layer_events_skip_features_that_fail_to_decode() {
+    let json = r#"{
+        "type": "layer_hover",
+        "layer_id": "parks",
+        "phase": "enter",
+        "feature_id": 1,
+        "properties": {"name": "Esplanadi"},
+        "latlng": {"lat": 60.167, "lng": 24.948},
+        "cursor_x": 10.0,
+        "cursor_y": 20.0,
+        "features": [
+            {"id": 1, "geometry": null, "properties": {"name": "Esplanadi"}, "source": "parks-source"},
+            {"id": 2, "source": 42}
+        ]
+    }"#;
+    let MapEvent::LayerHover(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected layer_hover variant");
+    };
+    assert_eq!(event.features.len(), 1);
+    assert_eq!(event.features[0].source, "parks-source");
+    assert!(event.features[0].geometry.is_none());
+}
+
+#[test]
+fn layer_click_event_defaults_without_features() {
+    let json = r#"{
+        "layer_id": "parks",
+        "feature_id": null,
+        "properties": {},
+        "latlng": {"lat": 60.0, "lng": 25.0}
+    }"#;
+    let event: LayerClickEvent = serde_json::from_str(json).unwrap();
+    assert!(event.features.is_empty());
+    assert!(!event.modifiers.any());
+}
+
+#[test]
+fn layer_hover_event_phases_from_js_format() {
+    let json = r#"{
+        "type": "layer_hover",
+        "layer_id": "parks",
+        "phase": "change",
+        "feature_id": 2,
+        "properties": {"name": "Kappeli"},
+        "latlng": {"lat": 60.167, "lng": 24.949},
+        "cursor_x": 10.0,
+        "cursor_y": 20.0
+    }"#;
+    let MapEvent::LayerHover(event) = serde_json::from_str(json).unwrap() else {
+        panic!("expected layer_hover variant");
+    };
+    assert_eq!(event.phase, LayerHoverPhase::Change);
+    assert!(!event.is_leave());
+
+    let leave = r#"{
+        "type": "layer_hover",
+        "layer_id": "parks",
+        "phase": "leave",
+        "feature_id": null,
+        "properties": null,
+        "latlng": {"lat": 60.167, "lng": 24.949},
+        "cursor_x": 10.0,
+        "cursor_y": 20.0
+    }"#;
+    let MapEvent::LayerHover(event) = serde_json::from_str(leave).unwrap() else {
+        panic!("expected layer_hover variant");
+    };
+    assert!(event.is_leave());
+}
@@ -0,0 +1,187 @@
+//! Unit tests for the style expression builder
+
+use dioxus_maplibre::{Expr, Interpolation, LayerOptions};
+use serde_json::json;
+
+fn to_json(expr: &Expr) -> serde_json::Value {
+    serde_json::to_value(expr).unwrap()
+}
+
+#[test]
+fn expr_lookups() {
+    assert_eq!(to_json(&Expr::get("name")), json!(["get", "name"]));
+    assert_eq!(to_json(&Expr::has("name")), json!(["has", "name"]));
+    assert_eq!(
+        to_json(&Expr::feature_state("hover")),
+        json!(["feature-state", "hover"])
+    );
+    assert_eq!(to_json(&Expr::zoom()), json!(["zoom"]));
+    assert_eq!(to_json(&Expr::geometry_type()), json!(["geometry-type"]));
+}
+
+#[test]
+fn expr_literals() {
+    assert_eq!(to_json(&Expr::from(5)), json!(5));
+    assert_eq!(to_json(&Expr::from("red")), json!("red"));
+    assert_eq!(to_json(&Expr::from(true)), json!(true));
+    assert_eq!(to_json(&Expr::literal([1, 2])), json!(["literal", [1, 2]]));
+}
+
+#[test]
+fn expr_comparisons() {
+    assert_eq!(
+        to_json(&Expr::get("kind").equals("park")),
+        json!(["==", ["get", "kind"], "park"])
+    );
+    assert_eq!(
+        to_json(&Expr::get("rank").less_or_equal(3)),
+        json!(["<=", ["get", "rank"], 3])
+    );
+    assert_eq!(to_json(&!Expr::has("name")), json!(["!", ["has", "name"]]));
+}
+
+#[test]
+fn expr_all_and_in() {
+    let filter = Expr::all([
+        Expr::has("name"),
+        Expr::get("class").is_in(["primary", "secondary"]),
+    ]);
+    assert_eq!(
+        to_json(&filter),
+        json!([
+            "all",
+            ["has", "name"],
+            [
+                "in",
+                ["get", "class"],
+                ["literal", ["primary", "secondary"]]
+            ]
+        ])
+    );
+}
+
+#[test]
+fn expr_math_operators() {
+    let expr = (Expr::get("size") * 2) + 1;
+    assert_eq!(to_json(&expr), json!(["+", ["*", ["get", "size"], 2], 1]));
+    assert_eq!(
+        to_json(&Expr::get("size").sqrt()),
+        json!(["sqrt", ["get", "size"]])
+    );
+}
+
+#[test]
+fn expr_match() {
+    let expr = Expr::match_(
+        Expr::get("type"),
+        [("park", "#22c55e"), ("water", "#3b82f6")],
+        "#888888",
+    );
+    assert_eq!(
+        to_json(&expr),
+        json!([
+            "match",
+            ["get", "type"],
+            "park",
+            "#22c55e",
+            "water",
+            "#3b82f6",
+            "#888888"
+        ])
+    );
+}
+
+#[test]
+fn expr_match_array_labels_are_bare() {
+    let expr = Expr::match_(
+        Expr::get("class"),
+        [(vec!["park", "garden"], 1), (vec!["water"], 2)],
+        0,
+    );
+    assert_eq!(
+        to_json(&expr),
+        json!([
+            "match",
+            ["get", "class"],
+            ["park", "garden"],
+            1,
+            ["water"],
+            2,
+            0
+        ])
+    );
+
+    let wrapped = Expr::match_(Expr::get("class"), [(Expr::literal([1, 2]), "a")], "b");
+    assert_eq!(
+        to_json(&wrapped),
+        json!(["match", ["get", "class"], [1, 2], "a", "b"])
+    );
+}
+
+#[test]
+fn expr_case_with_feature_state() {
+    let expr = Expr::case(
+        [(
+            Expr::coalesce([Expr::feature_state("hover"), Expr::from(false)]),
+            "#ff0000",
+        )],
+        "#0000ff",
+    );
+    assert_eq!(
+        to_json(&expr),
+        json!([
+            "case",
+            ["coalesce", ["feature-state", "hover"], false],
+            "#ff0000",
+            "#0000ff"
+        ])
+    );
+}
+
+#[test]
+fn expr_interpolate_exponential() {
+    let expr = Expr::interpolate(
+        Interpolation::Exponential(1.5),
+        Expr::zoom(),
+        [(10.0, 1.0), (18.0, 8.0)],
+    );
+    assert_eq!(
+        to_json(&expr),
+        json!([
+            "interpolate",
+            ["exponential", 1.5],
+            ["zoom"],
+            10.0,
+            1.0,
+            18.0,
+            8.0
+        ])
+    );
+}
+
+#[test]
+fn expr_step() {
+    let expr = Expr::step(Expr::get("point_count"), "#51bbd6", [(100.0, "#f1f075")]);
+    assert_eq!(
+        to_json(&expr),
+        json!(["step", ["get", "point_count"], "#51bbd6", 100.0, "#f1f075"])
+    );
+}
+
+#[test]
+fn expr_usable_in_layer_filter_and_paint() {
+    let layer = LayerOptions::circle("points", "source")
+        .filter(Expr::has("name"))
+        .paint(json!({ "circle-radius": Expr::get("size") * 2 }));
+    assert_eq!(layer.filter, Some(json!(["has", "name"])));
+    assert_eq!(
+        layer.paint,
+        Some(json!({ "circle-radius": ["*", ["get", "size"], 2] }))
+    );
+}
+
+#[test]
+fn expr_deserializes_raw_json() {
+    let expr: Expr = serde_json::from_value(json!(["get", "name"])).unwrap();
+    assert_eq!(expr, Expr::get("name"));
+}
@@ -2,10 +2,12 @@
 #![allow(clippy::float_cmp)]
 
 use dioxus_maplibre::{
-    ControlPosition, EaseToOptions, FeatureIdentifier, FitBoundsOptions, FlyToOptions, FogOptions,
-    GeoJsonSourceOptions, JumpToOptions, LatLng, LayerOptions, MarkerOptions, Padding,
-    PopupOptions, QueryOptions, RasterDemSourceOptions, RasterSourceOptions, SkyOptions,
-    TerrainOptions, VectorSourceOptions,
+    CirclePaint, CirclePitch, ControlPosition, EaseToOptions, Expr, FeatureIdentifier,
+    FitBoundsOptions, FlyToOptions, FogOptions, GeoJsonSourceOptions, JumpToOptions, LatLng,
+    LayerOptions, LayerSlot, LayerType, LineCap, LineLayout, LinePaint, MapStyle, MarkerOptions,
+    Padding, PopupOptions, PropertyValue, QueryOptions, RasterDemSourceOptions,
+    RasterSourceOptions, SkyOptions, StyleImageOptions, StyleSpecification, SymbolLayout,
+    TerrainOptions, VectorSourceOptions, Visibility,
 };
 use serde_json::json;
 
@@ -129,6 +131,95 @@ fn layer_options_builder_line() {
     assert!(layer.layout.is_some());
 }
 
+#[test]
+fn layer_options_typed_circle_paint() {
+    let layer = LayerOptions::circle("points", "source").circle_paint(CirclePaint {
+        circle_radius: Some(Expr::get("size").into()),
+        circle_color: Some("#3b82f6".into()),
+        circle_stroke_width: Some(1.into()),
+        circle_pitch_scale: Some(CirclePitch::Map.into()),
+        ..Default::default()
+    });
+
+    assert_eq!(layer.layer_type, LayerType::Circle);
+    assert_eq!(
+        layer.paint,
+        Some(json!({
+            "circle-radius": ["get", "size"],
+            "circle-color": "#3b82f6",
+            "circle-stroke-width": 1.0,
+            "circle-pitch-scale": "map"
+        }))
+    );
+}
+
+#[test]
+fn layer_options_typed_line_paint_and_layout() {
+    let layer = LayerOptions::line("route", "route-source")
+        .line_paint(LinePaint {
+            line_width: Some(3.0.into()),
+            line_dasharray: Some(vec![2.0, 1.0].into()),
+            ..Default::default()
+        })
+        .line_layout(LineLayout {
+            line_cap: Some(LineCap::Round.into()),
+            visibility: Some(Visibility::None.into()),
+            ..Default::default()
+        });
+
+    assert_eq!(
+        layer.paint,
+        Some(json!({"line-width": 3.0, "line-dasharray": [2.0, 1.0]}))
+    );
+    assert_eq!(
+        layer.layout,
+        Some(json!({"line-cap": "round", "visibility": "none"}))
+    );
+}
+
+#[test]
+fn layer_options_typed_symbol_layout() {
+    let layer = LayerOptions::symbol("labels", "places").symbol_layout(SymbolLayout {
+        text_field: Some(Expr::get("name").into()),
+        text_font: Some(vec!["Open Sans Regular".to_string()].into()),
+        text_allow_overlap: Some(true.into()),
+        ..Default::default()
+    });
+
+    assert_eq!(
+        layer.layout,
+        Some(json!({
+            "text-field": ["get", "name"],
+            "text-font": ["Open Sans Regular"],
+            "text-allow-overlap": true
+        }))
+    );
+}
+
+#[test]
+fn property_value_deserializes_literal_or_expression() {
+    let paint: CirclePaint = serde_json::from_value(json!({
+        "circle-radius": 4,
+        "circle-color": ["get", "color"]
+    }))
+    .unwrap();
+    assert_eq!(paint.circle_radius, Some(PropertyValue::Literal(4.0)));
+    assert_eq!(
+        paint.circle_color,
+        Some(PropertyValue::Expr(Expr::get("color")))
+    );
+}
+
+#[test]
+fn layer_type_roundtrip_keeps_unknown_types() {
+    let layer = LayerOptions::new("hills", "hillshade", "dem");
+    assert_eq!(layer.layer_type, LayerType::Hillshade);
+
+    let custom: LayerType = serde_json::from_str(r#""color-relief""#).unwrap();
+    assert_eq!(custom, LayerType::Other("color-relief".to_string()));
+    assert_eq!(serde_json::to_string(&custom).unwrap(), r#""color-relief""#);
+}
+
 #[test]
 fn layer_options_background() {
     let layer = LayerOptions::background("bg").paint(json!({"background-color": "#000"}));
@@ -158,6 +249,25 @@ fn layer_options_serialization_camel_case() {
     assert!(json.contains(r#""type":"circle""#));
 }
 
+#[test]
+fn layer_options_anchor_serialization() {
+    let layer = LayerOptions::fill("parks", "src")
+        .before_id("water")
+        .slot(LayerSlot::BelowLabels);
+
+    let json = serde_json::to_value(&layer).unwrap();
+    assert_eq!(json["beforeId"], "water");
+    assert_eq!(json["slot"], "below-labels");
+
+    let plain = serde_json::to_value(LayerOptions::fill("parks", "src")).unwrap();
+    assert!(plain.get("beforeId").is_none());
+    assert!(plain.get("slot").is_none());
+    assert_eq!(
+        serde_json::to_value(LayerSlot::AboveRoads).unwrap(),
+        "above-roads"
+    );
+}
+
 #[test]
 fn marker_options_default() {
     let opts = MarkerOptions::default();
@@ -284,7 +394,7 @@ fn fog_options_passthrough() {
 fn feature_identifier_serialization() {
     let feat = FeatureIdentifier {
         source: "my-source".to_string(),
-        id: 42,
+        id: 42.into(),
         source_layer: Some("points".to_string()),
     };
     let json = serde_json::to_string(&feat).unwrap();
@@ -297,13 +407,45 @@ fn feature_identifier_serialization() {
 fn feature_identifier_without_source_layer() {
     let feat = FeatureIdentifier {
         source: "geojson-src".to_string(),
-        id: 7,
+        id: 7.into(),
         source_layer: None,
     };
     let json = serde_json::to_string(&feat).unwrap();
     assert!(!json.contains("sourceLayer"));
 }
 
+#[test]
+fn feature_identifier_with_string_id() {
+    let feat = FeatureIdentifier {
+        source: "tiles".to_string(),
+        id: "building-12".into(),
+        source_layer: Some("buildings".to_string()),
+    };
+    let json = serde_json::to_string(&feat).unwrap();
+    assert!(json.contains(r#""id":"building-12""#));
+}
+
+#[test]
+fn style_image_options_serialize_nine_slice() {
+    let opts = StyleImageOptions {
+        pixel_ratio: Some(2.0),
+        stretch_x: Some(vec![[8.0, 24.0]]),
+        stretch_y: Some(vec![[8.0, 16.0]]),
+        content: Some([8.0, 8.0, 24.0, 16.0]),
+        ..Default::default()
+    };
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "pixelRatio": 2.0,
+            "stretchX": [[8.0, 24.0]],
+            "stretchY": [[8.0, 16.0]],
+            "content": [8.0, 8.0, 24.0, 16.0]
+        })
+    );
+}
+
 #[test]
 fn query_options_default_empty() {
     let opts = QueryOptions::default();
@@ -322,3 +464,43 @@ fn query_options_with_layers_and_filter() {
     assert!(json.contains("lines"));
     assert!(json.contains("active"));
 }
+
+#[test]
+fn style_specification_round_trips_unknown_keys() {
+    let style = json!({
+        "version": 8,
+        "name": "Basemap",
+        "sources": {
+            "osm": {"type": "raster", "tiles": ["https://tile.example/{z}/{x}/{y}.png"]}
+        },
+        "layers": [
+            {"id": "background", "type": "background"},
+            {"id": "osm", "type": "raster", "source": "osm", "metadata": {"group": "base"}}
+        ],
+        "glyphs": "https://fonts.example/{fontstack}/{range}.pbf",
+        "projection": {"type": "globe"},
+        "transition": {"duration": 300},
+        "terrain": {"source": "dem", "exaggeration": 1.5},
+        "state": {"highlight": {"default": false}}
+    });
+    let spec: StyleSpecification = serde_json::from_value(style.clone()).unwrap();
+    assert_eq!(spec.name.as_deref(), Some("Basemap"));
+    assert_eq!(spec.layer_ids().collect::<Vec<_>>(), ["background", "osm"]);
+    assert_eq!(spec.transition.and_then(|t| t.duration), Some(300));
+    assert!(spec.extra.contains_key("state"));
+    assert_eq!(serde_json::to_value(&spec).unwrap(), style);
+}
+
+#[test]
+fn style_specification_builder_defaults_to_version_8() {
+    let spec = StyleSpecification::default()
+        .source("points", json!({"type": "geojson", "data": null}))
+        .layer(json!({"id": "points", "type": "circle", "source": "points"}));
+    let json = serde_json::to_value(&spec).unwrap();
+    assert_eq!(json["version"], 8);
+    assert_eq!(json["layers"][0]["id"], "points");
+    assert_eq!(
+        MapStyle::from("https://a/style.json"),
+        MapStyle::Url("https://a/style.json".to_string())
+    );
+}
@@ -0,0 +1,781 @@
+//! Tests for the recording `MockMap` backend
+#![allow(clippy::float_cmp)]
+
+use std::cell::RefCell;
+use std::pin::pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+use dioxus_maplibre::testing::{MapCommand, MapEventHandlers, MockMap, use_mock_map};
+use dioxus_maplibre::{
+    FeatureIdentifier, FogOptions, GeoJsonSourceOptions, LatLng, LayerClickEvent, LayerHoverEvent,
+    LayerHoverOptions, LayerHoverPhase, LayerOptions, MapClickEvent, MapError, MapEvent,
+    MapFeatureState, MapFog, MapGestureEvent, MapImage, MapLayer, MapLoadStateEvent, MapMoveEvent,
+    MapSky, MapSource, MapSourceKind, MapState, MapStyle, MapTerrain, ModifierKeys, MovePhase,
+    Padding, Point, QueryOptions, SetStyleOptions, SkyOptions, StyleImageOptions, StyleImageSource,
+    TerrainOptions, use_map_handle, use_map_state,
+};
+use serde_json::json;
+
+fn settle(dom: &mut VirtualDom) {
+    for _ in 0..8 {
+        dom.process_events();
+        dom.render_immediate(&mut NoOpMutations);
+    }
+}
+
+#[test]
+fn handle_calls_are_recorded() {
+    let mock = MockMap::new();
+    let map = mock.handle();
+    map.add_layer(LayerOptions::circle("points", "source"));
+    map.zoom_to(12.0);
+    map.set_filter("points", json!(["has", "name"]));
+
+    assert_eq!(
+        mock.take_commands(),
+        vec![
+            MapCommand::AddLayer(LayerOptions::circle("points", "source")),
+            MapCommand::ZoomTo(12.0),
+            MapCommand::SetFilter {
+                layer_id: "points".to_string(),
+                filter: json!(["has", "name"]),
+            },
+        ]
+    );
+    assert!(mock.commands().is_empty());
+}
+
+/// Poll a mock-backed handle future, which completes without awaiting the bridge
+fn ready<F: Future>(future: F) -> F::Output {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("mock handle futures complete immediately"),
+    }
+}
+
+#[test]
+fn try_calls_are_recorded_and_succeed() {
+    let mock = MockMap::new();
+    let map = mock.handle();
+
+    assert_eq!(
+        ready(map.try_add_layer(LayerOptions::circle("points", "source"))),
+        Ok(())
+    );
+    assert_eq!(ready(map.try_remove_layer("points")), Ok(()));
+    assert_eq!(
+        mock.take_commands(),
+        vec![
+            MapCommand::AddLayer(LayerOptions::circle("points", "source")),
+            MapCommand::RemoveLayer {
+                id: "points".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn try_calls_return_scripted_failures() {
+    let mock = MockMap::new();
+    let map = mock.handle();
+    mock.fail(
+        "try_remove_layer",
+        MapError::LayerMissing {
+            id: "points".to_string(),
+        },
+    );
+
+    assert_eq!(
+        ready(map.try_remove_layer("points")),
+        Err(MapError::LayerMissing {
+            id: "points".to_string()
+        })
+    );
+    assert_eq!(ready(map.try_remove_layer("points")), Ok(()));
+    assert_eq!(mock.take_commands().len(), 2);
+}
+
+#[test]
+fn getters_return_scripted_values() {
+    let mock = MockMap::new();
+    let map = mock.handle();
+    mock.respond("get_zoom", 12.0);
+    mock.respond("get_center", LatLng::new(60.17, 24.94));
+    mock.respond("has_image", true);
+
+    assert_eq!(ready(map.get_zoom()), Some(12.0));
+    assert_eq!(ready(map.get_zoom()), None);
+    assert_eq!(ready(map.get_center()), Some(LatLng::new(60.17, 24.94)));
+    assert!(ready(map.has_image("pin")));
+    assert!(ready(map.query_rendered_features(QueryOptions::default())).is_empty());
+    assert!(mock.commands().is_empty());
+}
+
+#[test]
+fn bulk_feature_states_are_one_command() {
+    let mock = MockMap::new();
+    let map = mock.handle();
+    let states: Vec<_> = (1..=3)
+        .map(|id| {
+            let feature = FeatureIdentifier {
+                source: "points".to_string(),
+                id: id.into(),
+                source_layer: None,
+            };
+            (feature, json!({"selected": true}))
+        })
+        .collect();
+    map.set_feature_states(states.clone());
+    map.set_feature_states(Vec::new());
+    map.clear_feature_states("points", None);
+
+    assert_eq!(
+        mock.take_commands(),
+        vec![
+            MapCommand::SetFeatureStates(states),
+            MapCommand::ClearFeatureStates {
+                source: "points".to_string(),
+                source_layer: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn style_switches_record_their_options() {
+    let mock = MockMap::new();
+    let map = mock.handle();
+    let diff = SetStyleOptions {
+        diff: true,
+        transform_style: Some("return next;".to_string()),
+    };
+    map.set_style("https://example.com/light.json");
+    map.set_style_with("https://example.com/dark.json", diff.clone());
+    let invalid = MapError::InvalidStyleSpec {
+        message: "layers[0]: missing required property \"source\"".to_string(),
+    };
+    mock.fail("try_set_style", invalid.clone());
+    assert_eq!(
+        ready(map.try_set_style(
+            "https://example.com/broken.json",
+            SetStyleOptions::default()
+        )),
+        Err(invalid)
+    );
+
+    assert_eq!(
+        mock.take_commands(),
+        vec![
+            MapCommand::SetStyle {
+                style: MapStyle::from("https://example.com/light.json"),
+                options: SetStyleOptions::default(),
+            },
+            MapCommand::SetStyle {
+                style: MapStyle::from("https://example.com/dark.json"),
+                options: diff,
+            },
+            MapCommand::SetStyle {
+                style: MapStyle::from("https://example.com/broken.json"),
+                options: SetStyleOptions::default(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn batched_calls_are_recorded_as_one_command() {
+    let mock = MockMap::new();
+    mock.handle().batch(|b| {
+        b.zoom_in();
+        b.batch(|inner| inner.remove_layer("old"));
+    });
+
+    assert_eq!(
+        mock.commands(),
+        vec![MapCommand::Batch(vec![
+            MapCommand::ZoomIn,
+            MapCommand::RemoveLayer {
+                id: "old".to_string()
+            },
+        ])]
+    );
+}
+
+#[component]
+fn PointsScene(mock: MockMap) -> Element {
+    use_mock_map(&mock);
+    rsx! {
+        MapSource {
+            id: "points",
+            source: MapSourceKind::GeoJson(GeoJsonSourceOptions::default()),
+            MapLayer { options: LayerOptions::circle("points-layer", "points") }
+        }
+    }
+}
+
+#[test]
+fn declarative_components_record_commands() {
+    let mock = MockMap::new();
+    let mut dom = VirtualDom::new_with_props(PointsScene, PointsSceneProps { mock: mock.clone() });
+    dom.rebuild_in_place();
+    settle(&mut dom);
+
+    let commands = mock.commands();
+    assert!(commands.contains(&MapCommand::AddGeoJsonSource {
+        id: "points".to_string(),
+        options: GeoJsonSourceOptions::default(),
+    }));
+    assert!(
+        commands.contains(&MapCommand::AddLayer(LayerOptions::circle(
+            "points-layer",
+            "points"
+        )))
+    );
+}
+
+#[derive(Default)]
+struct Probe {
+    handlers: RefCell<Option<MapEventHandlers>>,
+    states: RefCell<Vec<MapState>>,
+    layer_events: RefCell<Vec<String>>,
+    show_layer: RefCell<Option<Signal<bool>>>,
+}
+
+#[derive(Props, Clone)]
+struct EventSceneProps {
+    mock: MockMap,
+    probe: Rc<Probe>,
+}
+
+impl PartialEq for EventSceneProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.mock == other.mock && Rc::ptr_eq(&self.probe, &other.probe)
+    }
+}
+
+/// Zooms in on every click, and records the state seen through `use_map_state`.
+#[component]
+fn EventScene(props: EventSceneProps) -> Element {
+    use_mock_map(&props.mock);
+    let state = use_map_state();
+    let map = use_map_handle();
+    props.probe.states.borrow_mut().push(state());
+
+    use_hook(|| {
+        let mut handlers = MapEventHandlers::default();
+        handlers.on_click = Some(EventHandler::new(move |_: MapClickEvent| {
+            if let Some(map) = &map {
+                map.zoom_in();
+            }
+        }));
+        *props.probe.handlers.borrow_mut() = Some(handlers);
+    });
+    rsx! {}
+}
+
+fn dispatch(dom: &VirtualDom, mock: &MockMap, probe: &Probe, event: MapEvent) {
+    let handlers = probe.handlers.borrow().clone().expect("scene rendered");
+    dom.in_scope(ScopeId::APP, || mock.dispatch(&handlers, event));
+}
+
+#[derive(Clone)]
+struct ToggleSceneProps<T: 'static> {
+    mock: MockMap,
+    initial: T,
+    render: fn(T) -> Element,
+    value: Rc<RefCell<Option<Signal<T>>>>,
+}
+
+/// Renders `render(value)` under a mock map, where `value` starts at `initial`.
+fn toggle_scene<T: Clone + 'static>(props: ToggleSceneProps<T>) -> Element {
+    let ToggleSceneProps {
+        mock,
+        initial,
+        render,
+        value: out,
+    } = props;
+    use_mock_map(&mock);
+    let value = use_signal(|| initial);
+    use_hook(|| *out.borrow_mut() = Some(value));
+    render(value())
+}
+
+/// Mount `render` under `mock` and settle it, returning the signal that drives it
+fn mount_with_toggle<T: Clone + 'static>(
+    mock: &MockMap,
+    initial: T,
+    render: fn(T) -> Element,
+) -> (VirtualDom, Signal<T>) {
+    let value = Rc::new(RefCell::new(None));
+    let mut dom = VirtualDom::new_with_props(
+        toggle_scene::<T>,
+        ToggleSceneProps {
+            mock: mock.clone(),
+            initial,
+            render,
+            value: value.clone(),
+        },
+    );
+    dom.rebuild_in_place();
+    settle(&mut dom);
+    let signal = value.borrow().expect("scene rendered");
+    (dom, signal)
+}
+
+#[test]
+fn injected_events_reach_handlers_and_map_state() {
+    let mock = MockMap::new();
+    let probe = Rc::new(Probe::default());
+    let mut dom = VirtualDom::new_with_props(
+        EventScene,
+        EventSceneProps {
+            mock: mock.clone(),
+            probe: probe.clone(),
+        },
+    );
+    dom.rebuild_in_place();
+    settle(&mut dom);
+
+    dispatch(
+        &dom,
+        &mock,
+        &probe,
+        MapEvent::Click(MapClickEvent {
+            latlng: LatLng::new(60.17, 24.94),
+            point: Point::new(10.0, 20.0),
+        }),
+    );
+    assert_eq!(mock.commands(), vec![MapCommand::ZoomIn]);
+
+    dispatch(
+        &dom,
+        &mock,
+        &probe,
+        MapEvent::MoveStart(MapGestureEvent {
+            user_initiated: true,
+            center: LatLng::new(60.17, 24.94),
+            zoom: 10.0,
+            bearing: 0.0,
+            pitch: 0.0,
+        }),
+    );
+    settle(&mut dom);
+    assert!(
+        probe
+            .states
+            .borrow()
+            .last()
+            .is_some_and(|state| state.is_moving)
+    );
+
+    dispatch(
+        &dom,
+        &mock,
+        &probe,
+        MapEvent::Move(MapMoveEvent {
+            center: LatLng::new(60.17, 24.94),
+            zoom: 14.0,
+            bearing: 0.0,
+            pitch: 0.0,
+            padding: Padding::default(),
+            bounds: None,
+            phase: MovePhase::MoveEnd,
+            seq: 1,
+            user_initiated: true,
+        }),
+    );
+    settle(&mut dom);
+    let last = probe
+        .states
+        .borrow()
+        .last()
+        .copied()
+        .expect("scene rendered");
+    assert_eq!(last.zoom, 14.0);
+    assert!(!last.is_moving);
+}
+
+/// A clickable `parks` layer that can be unmounted through `probe.show_layer`.
+#[component]
+fn LayerScene(props: EventSceneProps) -> Element {
+    use_mock_map(&props.mock);
+    let show_layer = use_signal(|| true);
+    use_hook(|| {
+        *props.probe.handlers.borrow_mut() = Some(MapEventHandlers::default());
+        *props.probe.show_layer.borrow_mut() = Some(show_layer);
+    });
+
+    let on_click_log = props.probe.clone();
+    let on_leave_log = props.probe;
+    rsx! {
+        if show_layer() {
+            MapLayer {
+                options: LayerOptions::fill("parks", "parks-source"),
+                on_click: move |event: LayerClickEvent| {
+                    on_click_log.layer_events.borrow_mut().push(format!("click {}", event.layer_id));
+                },
+                on_mouse_leave: move |event: LayerHoverEvent| {
+                    on_leave_log.layer_events.borrow_mut().push(format!("leave {}", event.layer_id));
+                },
+            }
+        }
+    }
+}
+
+fn layer_click(layer_id: &str) -> MapEvent {
+    MapEvent::LayerClick(LayerClickEvent {
+        layer_id: layer_id.to_string(),
+        feature_id: Some(7.into()),
+        properties: json!({"name": "Kaivopuisto"}),
+        latlng: LatLng::new(60.16, 24.95),
+        point: Point::new(120.0, 80.0),
+        modifiers: ModifierKeys::default(),
+        features: Vec::new(),
+    })
+}
+
+#[test]
+fn layer_handlers_receive_only_their_layer_events() {
+    let mock = MockMap::new();
+    let probe = Rc::new(Probe::default());
+    let mut dom = VirtualDom::new_with_props(
+        LayerScene,
+        EventSceneProps {
+            mock: mock.clone(),
+            probe: probe.clone(),
+        },
+    );
+    dom.rebuild_in_place();
+    settle(&mut dom);
+
+    let commands = mock.take_commands();
+    assert!(commands.contains(&MapCommand::OnLayerClick {
+        layer_id: "parks".to_string()
+    }));
+    assert!(commands.contains(&MapCommand::OnLayerHover {
+        layer_id: "parks".to_string(),
+        options: LayerHoverOptions::default(),
+    }));
+    assert!(!commands.contains(&MapCommand::OnLayerDblClick {
+        layer_id: "parks".to_string()
+    }));
+
+    dispatch(&dom, &mock, &probe, layer_click("parks"));
+    dispatch(&dom, &mock, &probe, layer_click("roads"));
+    dispatch(
+        &dom,
+        &mock,
+        &probe,
+        MapEvent::LayerHover(LayerHoverEvent {
+            layer_id: "parks".to_string(),
+            phase: LayerHoverPhase::Leave,
+            feature_id: None,
+            properties: None,
+            latlng: LatLng::new(0.0, 0.0),
+            cursor_x: 0.0,
+            cursor_y: 0.0,
+            point: Point::new(0.0, 0.0),
+            modifiers: ModifierKeys::default(),
+            features: Vec::new(),
+        }),
+    );
+    assert_eq!(
+        *probe.layer_events.borrow(),
+        vec!["click parks".to_string(), "leave parks".to_string()]
+    );
+
+    let mut show_layer = probe.show_layer.borrow().expect("scene rendered");
+    dom.in_scope(ScopeId::APP, || show_layer.set(false));
+    settle(&mut dom);
+    assert!(mock.commands().contains(&MapCommand::OffLayerClick {
+        layer_id: "parks".to_string()
+    }));
+
+    dispatch(&dom, &mock, &probe, layer_click("parks"));
+    assert_eq!(probe.layer_events.borrow().len(), 2);
+}
+
+/// Two `MapLayer`s sharing the ID `parks`; the first can be unmounted
+/// without re-rendering the second.
+#[component]
+fn SharedLayerIdScene(props: EventSceneProps) -> Element {
+    use_mock_map(&props.mock);
+    use_hook(|| *props.probe.handlers.borrow_mut() = Some(MapEventHandlers::default()));
+
+    let new_log = props.probe.clone();
+    rsx! {
+        OldParksLayer { mock: props.mock, probe: props.probe }
+        MapLayer {
+            options: LayerOptions::fill("parks", "parks-source"),
+            on_click: move |_| new_log.layer_events.borrow_mut().push("new".to_string()),
+        }
+    }
+}
+
+#[component]
+fn OldParksLayer(props: EventSceneProps) -> Element {
+    let show_layer = use_signal(|| true);
+    use_hook(|| *props.probe.show_layer.borrow_mut() = Some(show_layer));
+
+    let old_log = props.probe;
+    rsx! {
+        if show_layer() {
+            MapLayer {
+                options: LayerOptions::fill("parks", "parks-source"),
+                on_click: move |_| old_log.layer_events.borrow_mut().push("old".to_string()),
+            }
+        }
+    }
+}
+
+#[test]
+fn unmounting_a_layer_keeps_handlers_registered_by_another() {
+    let mock = MockMap::new();
+    let probe = Rc::new(Probe::default());
+    let mut dom = VirtualDom::new_with_props(
+        SharedLayerIdScene,
+        EventSceneProps {
+            mock: mock.clone(),
+            probe: probe.clone(),
+        },
+    );
+    dom.rebuild_in_place();
+    settle(&mut dom);
+
+    let mut show_layer = probe.show_layer.borrow().expect("scene rendered");
+    dom.in_scope(ScopeId::APP, || show_layer.set(false));
+    settle(&mut dom);
+
+    dispatch(&dom, &mock, &probe, layer_click("parks"));
+    assert_eq!(*probe.layer_events.borrow(), vec!["new".to_string()]);
+}
+
+fn selected_point() -> FeatureIdentifier {
+    FeatureIdentifier {
+        source: "points".to_string(),
+        id: 1.into(),
+        source_layer: None,
+    }
+}
+
+#[test]
+fn feature_state_component_sets_and_removes_its_keys() {
+    let mock = MockMap::new();
+    let (mut dom, mut state) =
+        mount_with_toggle(&mock, Some(json!({"selected": true, "rank": 1})), |state| {
+            rsx! {
+                if let Some(state) = state {
+                    MapFeatureState { feature: selected_point(), state }
+                }
+            }
+        });
+    assert_eq!(
+        mock.take_commands(),
+        vec![MapCommand::SetFeatureState {
+            feature: selected_point(),
+            state: json!({"selected": true, "rank": 1}),
+        }]
+    );
+
+    let load_state = |dom: &mut VirtualDom, style_loaded| {
+        let event = MapEvent::LoadState(MapLoadStateEvent {
+            style_loaded,
+            tiles_loaded: false,
+        });
+        dom.in_scope(ScopeId::APP, || {
+            mock.dispatch(&MapEventHandlers::default(), event);
+        });
+        settle(dom);
+    };
+    load_state(&mut dom, true);
+    load_state(&mut dom, false);
+    assert_eq!(mock.take_commands().len(), 1);
+    load_state(&mut dom, true);
+    assert_eq!(
+        mock.take_commands(),
+        vec![MapCommand::SetFeatureState {
+            feature: selected_point(),
+            state: json!({"selected": true, "rank": 1}),
+        }]
+    );
+
+    dom.in_scope(ScopeId::APP, || state.set(Some(json!({"selected": true}))));
+    settle(&mut dom);
+    assert_eq!(
+        mock.take_commands(),
+        vec![
+            MapCommand::RemoveFeatureStateKey {
+                feature: selected_point(),
+                key: "rank".to_string(),
+            },
+            MapCommand::SetFeatureState {
+                feature: selected_point(),
+                state: json!({"selected": true}),
+            },
+        ]
+    );
+
+    dom.in_scope(ScopeId::APP, || state.set(None));
+    settle(&mut dom);
+    assert_eq!(
+        mock.take_commands(),
+        vec![MapCommand::RemoveFeatureStateKey {
+            feature: selected_point(),
+            key: "selected".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn atmosphere_components_apply_update_and_remove() {
+    let mock = MockMap::new();
+    let (mut dom, mut fog) =
+        mount_with_toggle(&mock, Some(FogOptions(json!({"range": [1, 10]}))), |fog| {
+            rsx! {
+                MapTerrain {
+                    options: TerrainOptions {
+                        source: "dem".to_string(),
+                        exaggeration: Some(1.5),
+                    },
+                }
+                if let Some(options) = fog {
+                    MapFog { options }
+                }
+            }
+        });
+    let commands = mock.take_commands();
+    assert!(commands.contains(&MapCommand::SetTerrain(TerrainOptions {
+        source: "dem".to_string(),
+        exaggeration: Some(1.5),
+    })));
+    assert!(commands.contains(&MapCommand::SetFog(FogOptions(json!({"range": [1, 10]})))));
+
+    let thicker = FogOptions(json!({"range": [0.5, 5]}));
+    dom.in_scope(ScopeId::APP, || fog.set(Some(thicker.clone())));
+    settle(&mut dom);
+    assert_eq!(mock.take_commands(), vec![MapCommand::SetFog(thicker)]);
+
+    dom.in_scope(ScopeId::APP, || fog.set(None));
+    settle(&mut dom);
+    assert_eq!(mock.take_commands(), vec![MapCommand::RemoveFog]);
+}
+
+#[test]
+fn sky_component_applies_updates_and_removes_sky() {
+    let mock = MockMap::new();
+    let day = SkyOptions(json!({"sky-color": "#88c6fc"}));
+    let (mut dom, mut sky) = mount_with_toggle(&mock, Some(day.clone()), |sky| {
+        rsx! {
+            if let Some(options) = sky {
+                MapSky { options }
+            }
+        }
+    });
+    assert_eq!(mock.take_commands(), vec![MapCommand::SetSky(day)]);
+
+    let dusk = SkyOptions(json!({"sky-color": "#f4a261", "horizon-blend": 0.3}));
+    dom.in_scope(ScopeId::APP, || sky.set(Some(dusk.clone())));
+    settle(&mut dom);
+    assert_eq!(mock.take_commands(), vec![MapCommand::SetSky(dusk)]);
+
+    dom.in_scope(ScopeId::APP, || sky.set(None));
+    settle(&mut dom);
+    assert_eq!(mock.take_commands(), vec![MapCommand::RemoveSky]);
+}
+
+#[test]
+fn rgba_images_must_match_their_dimensions() {
+    let mock = MockMap::new();
+    let map = mock.handle();
+    let rgba = |data: Vec<u8>| StyleImageSource::Rgba {
+        width: 2,
+        height: 1,
+        data,
+    };
+    map.add_image("short", rgba(vec![0; 7]), StyleImageOptions::default());
+    map.add_image("dot", rgba(vec![255; 8]), StyleImageOptions::default());
+    assert!(matches!(
+        ready(map.try_add_image("short", rgba(vec![0; 7]), StyleImageOptions::default())),
+        Err(MapError::InvalidStyleSpec { .. })
+    ));
+    assert_eq!(
+        ready(map.try_add_image("dot", rgba(vec![255; 8]), StyleImageOptions::default())),
+        Ok(())
+    );
+
+    let add_dot = MapCommand::AddImage {
+        id: "dot".to_string(),
+        source: rgba(vec![255; 8]),
+        options: StyleImageOptions::default(),
+    };
+    assert_eq!(mock.take_commands(), vec![add_dot.clone(), add_dot]);
+}
+
+#[test]
+fn image_component_adds_and_removes_image() {
+    let mock = MockMap::new();
+    let (mut dom, mut show_image) = mount_with_toggle(&mock, true, |show_image| {
+        rsx! {
+            if show_image {
+                MapImage {
+                    id: "pin",
+                    source: StyleImageSource::Svg("<svg/>".to_string()),
+                    options: StyleImageOptions {
+                        pixel_ratio: Some(2.0),
+                        sdf: Some(true),
+                        ..Default::default()
+                    },
+                }
+            }
+        }
+    });
+    assert_eq!(
+        mock.take_commands(),
+        vec![MapCommand::AddImage {
+            id: "pin".to_string(),
+            source: StyleImageSource::Svg("<svg/>".to_string()),
+            options: StyleImageOptions {
+                pixel_ratio: Some(2.0),
+                sdf: Some(true),
+                ..Default::default()
+            },
+        }]
+    );
+
+    dom.in_scope(ScopeId::APP, || show_image.set(false));
+    settle(&mut dom);
+    assert_eq!(
+        mock.take_commands(),
+        vec![MapCommand::RemoveImage {
+            id: "pin".to_string()
+        }]
+    );
+}
+
+#[test]
+fn image_component_skips_invalid_images() {
+    let mock = MockMap::new();
+    let (mut dom, mut show_image) = mount_with_toggle(&mock, true, |show_image| {
+        rsx! {
+            if show_image {
+                MapImage {
+                    id: "broken",
+                    source: StyleImageSource::Rgba {
+                        width: 2,
+                        height: 2,
+                        data: vec![0; 3],
+                    },
+                }
+            }
+        }
+    });
+    assert!(mock.take_commands().is_empty());
+
+    // Nothing was added, so unmounting must not remove an image by that ID
+    dom.in_scope(ScopeId::APP, || show_image.set(false));
+    settle(&mut dom);
+    assert!(mock.take_commands().is_empty());
+}
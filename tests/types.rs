@@ -1,7 +1,11 @@
 //! Unit tests for geographic types
 #![allow(clippy::float_cmp)]
 
-use dioxus_maplibre::{Bounds, LatLng, MapPosition, Point, QueryFeature};
+use dioxus_maplibre::{
+    Bounds, Feature, FeatureCollection, FeatureId, Geometry, LatLng, MapPosition, MapState, Point,
+    Position, QueryFeature,
+};
+use serde::{Deserialize, Serialize};
 
 #[test]
 fn latlng_new() {
@@ -72,6 +76,38 @@ fn map_position_default() {
     assert_eq!(pos.zoom, 10.0);
 }
 
+#[test]
+fn map_position_with_bearing_and_pitch() {
+    let pos = MapPosition::new(LatLng::new(60.17, 24.94), 12.0)
+        .with_bearing(90.0)
+        .with_pitch(45.0);
+    assert_eq!(pos.bearing, 90.0);
+    assert_eq!(pos.pitch, 45.0);
+}
+
+#[test]
+fn map_position_deserialize_without_camera_angles() {
+    let pos: MapPosition =
+        serde_json::from_str(r#"{"center": {"lat": 60.17, "lng": 24.94}, "zoom": 12.0}"#).unwrap();
+    assert_eq!(pos, MapPosition::new(LatLng::new(60.17, 24.94), 12.0));
+}
+
+#[test]
+fn map_state_from_position() {
+    let position = MapPosition::new(LatLng::new(60.17, 24.94), 12.0).with_pitch(30.0);
+    let state = MapState::from(position);
+    assert_eq!(state.position(), position);
+    assert_eq!(state.bounds, None);
+    assert!(!state.is_moving);
+    assert!(!state.is_style_loaded);
+    assert!(!state.tiles_loaded);
+}
+
+#[test]
+fn map_state_default_uses_default_position() {
+    assert_eq!(MapState::default().position(), MapPosition::default());
+}
+
 #[test]
 fn bounds_new() {
     let sw = LatLng::new(60.0, 24.0);
@@ -156,11 +192,36 @@ fn query_feature_deserialize() {
         "sourceLayer": "places"
     }"#;
     let feature: QueryFeature = serde_json::from_str(json).unwrap();
-    assert_eq!(feature.id, Some(42));
+    assert_eq!(feature.id, Some(FeatureId::Number(42)));
     assert_eq!(feature.source, "cities");
     assert_eq!(feature.source_layer.as_deref(), Some("places"));
 }
 
+#[test]
+fn query_feature_deserialize_string_id() {
+    let json = r#"{
+        "id": "way/1234",
+        "geometry": {"type": "Point", "coordinates": [24.94, 60.17]},
+        "properties": {},
+        "source": "osm",
+        "sourceLayer": "buildings"
+    }"#;
+    let feature: QueryFeature = serde_json::from_str(json).unwrap();
+    let id = feature.id.unwrap();
+    assert_eq!(id.as_str(), Some("way/1234"));
+    assert_eq!(id.as_number(), None);
+    assert_eq!(id.to_string(), "way/1234");
+}
+
+#[test]
+fn feature_id_serializes_untagged() {
+    assert_eq!(serde_json::to_string(&FeatureId::from(3)).unwrap(), "3");
+    assert_eq!(
+        serde_json::to_string(&FeatureId::from("a")).unwrap(),
+        r#""a""#
+    );
+}
+
 #[test]
 fn query_feature_without_optional_fields() {
     let json = r#"{
@@ -173,3 +234,143 @@ fn query_feature_without_optional_fields() {
     assert_eq!(feature.source_layer, None);
     assert_eq!(feature.source, "my-source");
 }
+
+#[test]
+fn query_feature_geometry_is_typed() {
+    let json = r#"{
+        "geometry": {"type": "Point", "coordinates": [24.94, 60.17]},
+        "properties": {"name": "Helsinki"},
+        "source": "cities"
+    }"#;
+    let feature: QueryFeature = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        feature.geometry.and_then(|geometry| geometry.as_point()),
+        Some(LatLng::new(60.17, 24.94))
+    );
+}
+
+#[test]
+fn query_feature_allows_null_geometry() {
+    let json = r#"{"geometry": null, "properties": {}, "source": "cities"}"#;
+    let feature: QueryFeature = serde_json::from_str(json).unwrap();
+    assert!(feature.geometry.is_none());
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct City {
+    name: String,
+}
+
+#[test]
+fn query_feature_into_typed_feature() {
+    let json = r#"{
+        "id": 7,
+        "geometry": {"type": "Point", "coordinates": [24.94, 60.17]},
+        "properties": {"name": "Helsinki"},
+        "source": "cities"
+    }"#;
+    let feature: QueryFeature = serde_json::from_str(json).unwrap();
+    let feature = feature.into_feature::<City>().unwrap();
+    assert_eq!(feature.id, Some(FeatureId::Number(7)));
+    assert_eq!(feature.properties.name, "Helsinki");
+}
+
+#[test]
+fn position_serializes_as_lng_lat_array() {
+    let json = serde_json::to_value(Position::from(LatLng::new(60.17, 24.94))).unwrap();
+    assert_eq!(json, serde_json::json!([24.94, 60.17]));
+}
+
+#[test]
+fn position_deserializes_altitude() {
+    let pos: Position = serde_json::from_str("[24.94, 60.17, 12.5]").unwrap();
+    assert_eq!(pos.altitude, Some(12.5));
+    assert_eq!(pos.to_latlng(), LatLng::new(60.17, 24.94));
+    assert!(serde_json::from_str::<Position>("[24.94]").is_err());
+}
+
+#[test]
+fn geometry_polygon_closes_ring() {
+    let geometry = Geometry::polygon([
+        LatLng::new(0.0, 0.0),
+        LatLng::new(0.0, 1.0),
+        LatLng::new(1.0, 1.0),
+    ]);
+    let Geometry::Polygon { coordinates } = geometry else {
+        panic!("expected polygon");
+    };
+    assert_eq!(coordinates[0].len(), 4);
+    assert_eq!(coordinates[0][0], coordinates[0][3]);
+}
+
+#[test]
+fn geometry_bounds_roundtrip() {
+    let bounds = Bounds::new(LatLng::new(60.0, 24.0), LatLng::new(61.0, 25.0));
+    let geometry = Geometry::from(bounds);
+    assert_eq!(geometry.bounds(), Some(bounds));
+}
+
+#[test]
+fn geometry_collection_deserialize() {
+    let json = r#"{
+        "type": "GeometryCollection",
+        "geometries": [
+            {"type": "Point", "coordinates": [24.0, 60.0]},
+            {"type": "LineString", "coordinates": [[25.0, 61.0], [26.0, 62.0]]}
+        ]
+    }"#;
+    let geometry: Geometry = serde_json::from_str(json).unwrap();
+    let bounds = geometry.bounds().unwrap();
+    assert_eq!(bounds.sw, LatLng::new(60.0, 24.0));
+    assert_eq!(bounds.ne, LatLng::new(62.0, 26.0));
+}
+
+#[test]
+fn feature_collection_serializes_as_geojson() {
+    let collection = FeatureCollection::new(vec![
+        Feature::new(
+            LatLng::new(60.17, 24.94),
+            City {
+                name: "Helsinki".to_string(),
+            },
+        )
+        .with_id(1),
+    ]);
+    let json = serde_json::Value::from(collection);
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "id": 1,
+                "geometry": {"type": "Point", "coordinates": [24.94, 60.17]},
+                "properties": {"name": "Helsinki"}
+            }]
+        })
+    );
+}
+
+#[test]
+fn feature_collection_deserialize_with_null_geometry() {
+    let json = r#"{
+        "type": "FeatureCollection",
+        "features": [
+            {"type": "Feature", "geometry": null, "properties": {"name": "Nowhere"}}
+        ]
+    }"#;
+    let collection: FeatureCollection<City> = serde_json::from_str(json).unwrap();
+    assert_eq!(collection.features[0].geometry, None);
+    assert_eq!(collection.bounds(), None);
+}
+
+#[test]
+fn feature_deserialize_with_null_or_missing_properties() {
+    let json = r#"{"type": "Feature", "geometry": null, "properties": null}"#;
+    let feature: Feature = serde_json::from_str(json).unwrap();
+    assert_eq!(feature.properties, serde_json::Value::Null);
+
+    let json = r#"{"type": "Feature", "geometry": null}"#;
+    let feature: Feature<City> = serde_json::from_str(json).unwrap();
+    assert_eq!(feature.properties, City::default());
+}